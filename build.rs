@@ -11,8 +11,25 @@ fn main() {
     if fs::create_dir(Path::new(&dir).join("lib")).is_err_and(|e| e.kind() != ErrorKind::AlreadyExists) {
         panic!("Could not create lib/ directory.");
     }
-    Command::new("make").args(&["PLATFORM=PLATFORM_DESKTOP", "-C", &format!("{}/raylib/src", dir)]).status().unwrap();
-    Command::new("cp").args(&[&format!("{}/raylib/src/libraylib.a", dir), &format!("{}/lib/", dir)]).status().unwrap();
-    Command::new("cp").args(&[&format!("{}/raylib/src/raylib.h", dir), &format!("{}/lib/", dir)]).status().unwrap();
+
+    // With the null-audio feature, miniaudio is built with only its null backend, for machines without a sound card.
+    let null_audio = env::var("CARGO_FEATURE_NULL_AUDIO").is_ok();
+    let audio_backend = if null_audio { "null" } else { "default" };
+
+    // make can't tell the backend changed, so start from a clean raylib when it does.
+    let backend_marker = Path::new(&dir).join("lib").join("audio-backend");
+    if fs::read_to_string(&backend_marker).map_or(true, |backend| backend != audio_backend) {
+        Command::new("make").args(["clean", "-C", &format!("{}/raylib/src", dir)]).status().unwrap();
+    }
+
+    let mut make_args = vec!["PLATFORM=PLATFORM_DESKTOP".to_string(), "-C".to_string(), format!("{}/raylib/src", dir)];
+    if null_audio {
+        make_args.push("CUSTOM_CFLAGS=-DMA_ENABLE_ONLY_SPECIFIC_BACKENDS -DMA_ENABLE_NULL".to_string());
+    }
+    if Command::new("make").args(&make_args).status().unwrap().success() {
+        fs::write(&backend_marker, audio_backend).unwrap();
+    }
+    Command::new("cp").args([&format!("{}/raylib/src/libraylib.a", dir), &format!("{}/lib/", dir)]).status().unwrap();
+    Command::new("cp").args([&format!("{}/raylib/src/raylib.h", dir), &format!("{}/lib/", dir)]).status().unwrap();
     println!("cargo:rustc-link-search=native={}", Path::new(&dir).join("lib").display());
 }
@@ -1,10 +1,21 @@
 #![allow(non_camel_case_types)]
 
-use libc::{c_int, c_float, c_void};
-use std::ffi::CString;
-use std::ops::{Add, Sub, Mul, Div, AddAssign, SubAssign, MulAssign, DivAssign};
+use libc::{c_char, c_int, c_uint, c_uchar, c_ushort, c_float, c_void};
+use std::ffi::{CString, NulError};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::slice;
+use std::fmt;
+use std::fs;
+use std::marker::PhantomData;
+use std::rc::Rc;
+use std::mem::{self, ManuallyDrop};
+use std::ops::{Add, Sub, Mul, Div, Neg, AddAssign, SubAssign, MulAssign, DivAssign, Deref, DerefMut};
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, Ordering};
 
 #[repr(C)]
+#[derive(Clone)]
 struct CColor {
     r: u8,
     g: u8,
@@ -18,6 +29,169 @@ struct CVector2 {
     y: f32,
 }
 
+#[repr(C)]
+struct CCamera2D {
+    offset: CVector2,
+    target: CVector2,
+    rotation: c_float,
+    zoom: c_float,
+}
+
+#[repr(C)]
+struct CCamera3D {
+    position: Vector3,
+    target: Vector3,
+    up: Vector3,
+    fovy: c_float,
+    projection: c_int,
+}
+
+#[repr(C)]
+struct CRayCollision {
+    hit: bool,
+    distance: c_float,
+    point: Vector3,
+    normal: Vector3,
+}
+
+/*================================
+      Audio stuff, internals.
+  ================================*/
+
+#[repr(C)]
+#[derive(Clone)]
+struct CWave {
+    frame_count: c_uint,
+    sample_rate: c_uint,
+    sample_size: c_uint,
+    channels: c_uint,
+    data: *mut c_void,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+struct CAudioStream {
+    buffer: *mut c_void,        // rAudioBuffer, opaque to us.
+    processor: *mut c_void,     // rAudioProcessor, likewise.
+
+    sample_rate: c_uint,
+    sample_size: c_uint,
+    channels: c_uint,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+struct CSound {
+    stream: CAudioStream,
+    frame_count: c_uint,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+struct CMusic {
+    stream: CAudioStream,
+    frame_count: c_uint,
+    looping: bool,
+
+    ctx_type: c_int,
+    ctx_data: *mut c_void,
+}
+
+type CAudioCallback = extern "C" fn(buffer_data: *mut c_void, frames: c_uint);
+
+/*================================
+      Model stuff, internals.
+  ================================*/
+
+#[repr(C)]
+#[derive(Clone)]
+struct CMesh {
+    vertex_count: c_int,
+    triangle_count: c_int,
+
+    vertices: *mut c_float,
+    texcoords: *mut c_float,
+    texcoords2: *mut c_float,
+    normals: *mut c_float,
+    tangents: *mut c_float,
+    colors: *mut c_uchar,
+    indices: *mut c_ushort,
+
+    anim_vertices: *mut c_float,
+    anim_normals: *mut c_float,
+    bone_ids: *mut c_uchar,
+    bone_weights: *mut c_float,
+    bone_matrices: *mut Matrix,
+    bone_count: c_int,
+
+    vao_id: c_uint,
+    vbo_id: *mut c_uint,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+struct CShader {
+    id: c_uint,
+    locs: *mut c_int,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+struct CMaterialMap {
+    texture: CTexture2D,
+    color: CColor,
+    value: c_float,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+struct CMaterial {
+    shader: CShader,
+    maps: *mut CMaterialMap,
+    params: [c_float; 4],
+}
+
+#[repr(C)]
+#[derive(Clone)]
+struct CTransform {
+    translation: Vector3,
+    rotation: Quaternion,
+    scale: Vector3,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+struct CBoneInfo {
+    name: [c_char; 32],
+    parent: c_int,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+struct CModel {
+    transform: Matrix,
+
+    mesh_count: c_int,
+    material_count: c_int,
+    meshes: *mut CMesh,
+    materials: *mut CMaterial,
+    mesh_material: *mut c_int,
+
+    bone_count: c_int,
+    bones: *mut CBoneInfo,
+    bind_pose: *mut CTransform,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+struct CModelAnimation {
+    bone_count: c_int,
+    frame_count: c_int,
+    bones: *mut CBoneInfo,
+    frame_poses: *mut *mut CTransform,
+    name: [c_char; 32],
+}
+
 /*================================
        Font stuff, internals.
   ================================*/
@@ -34,6 +208,16 @@ struct CTexture {
 
 type CTexture2D = CTexture;
 
+#[repr(C)]
+#[derive(Clone)]
+struct CRenderTexture {
+    id: u32,
+    texture: CTexture,
+    depth: CTexture,
+}
+
+type CRenderTexture2D = CRenderTexture;
+
 #[repr(C)]
 struct CRectangle {
     x: f32,
@@ -43,8 +227,9 @@ struct CRectangle {
 }
 
 #[repr(C)]
+#[derive(Clone)]
 struct CImage {
-    data: c_void,
+    data: *mut c_void,
     width: i32,
     height: i32,
     mipmaps: i32,
@@ -97,13 +282,392 @@ extern {
     fn GetCharPressed() -> c_int;
     fn IsKeyPressed(key: c_int) -> bool;
     fn IsKeyPressedRepeat(key: c_int) -> bool;
+
+    // Window.
+    fn IsWindowReady() -> bool;
+    fn SetConfigFlags(flags: c_uint);
+    fn SetWindowMinSize(width: c_int, height: c_int);
+    fn SetWindowMaxSize(width: c_int, height: c_int);
+    fn SetWindowPosition(x: c_int, y: c_int);
+    fn SetTargetFPS(fps: c_int);
+    fn SetExitKey(key: c_int);
+    fn ToggleFullscreen();
+    fn ToggleBorderlessWindowed();
+    fn MaximizeWindow();
+    fn MinimizeWindow();
+    fn RestoreWindow();
+    fn SetWindowTitle(title: *const i8);
+    fn SetWindowSize(width: c_int, height: c_int);
+    fn SetWindowOpacity(opacity: c_float);
+    fn SetWindowMonitor(monitor: c_int);
+    fn SetWindowFocused();
+    fn SetWindowState(flags: c_uint);
+    fn ClearWindowState(flags: c_uint);
+    fn IsWindowResized() -> bool;
+    fn IsWindowFocused() -> bool;
+    fn IsWindowHidden() -> bool;
+    fn IsWindowMinimized() -> bool;
+    fn IsWindowMaximized() -> bool;
+    fn IsWindowFullscreen() -> bool;
+    fn IsWindowState(flag: c_uint) -> bool;
+    fn GetWindowPosition() -> CVector2;
+    fn GetMonitorCount() -> c_int;
+    fn GetCurrentMonitor() -> c_int;
+
+    // Shapes.
+    fn DrawPixel(pos_x: c_int, pos_y: c_int, color: CColor);
+    fn DrawPixelV(position: CVector2, color: CColor);
+    fn DrawLine(start_pos_x: c_int, start_pos_y: c_int, end_pos_x: c_int, end_pos_y: c_int, color: CColor);
+    fn DrawLineV(start_pos: CVector2, end_pos: CVector2, color: CColor);
+    fn DrawLineStrip(points: *const CVector2, point_count: c_int, color: CColor);
+    fn DrawLineBezier(start_pos: CVector2, end_pos: CVector2, thickness: c_float, color: CColor);
+    fn DrawCircleV(center: CVector2, radius: c_float, color: CColor);
+    fn DrawCircleSector(center: CVector2, radius: c_float, start_angle: c_float, end_angle: c_float, segments: c_int, color: CColor);
+    fn DrawCircleSectorLines(center: CVector2, radius: c_float, start_angle: c_float, end_angle: c_float, segments: c_int, color: CColor);
+    fn DrawCircleGradient(center_x: c_int, center_y: c_int, radius: c_float, inner: CColor, outer: CColor);
+    fn DrawCircleLines(center_x: c_int, center_y: c_int, radius: c_float, color: CColor);
+    fn DrawCircleLinesV(center: CVector2, radius: c_float, color: CColor);
+    fn DrawEllipse(center_x: c_int, center_y: c_int, radius_h: c_float, radius_v: c_float, color: CColor);
+    fn DrawEllipseLines(center_x: c_int, center_y: c_int, radius_h: c_float, radius_v: c_float, color: CColor);
+    fn DrawRing(center: CVector2, inner_radius: c_float, outer_radius: c_float, start_angle: c_float, end_angle: c_float, segments: c_int, color: CColor);
+    fn DrawRingLines(center: CVector2, inner_radius: c_float, outer_radius: c_float, start_angle: c_float, end_angle: c_float, segments: c_int, color: CColor);
+    fn DrawRectangleV(position: CVector2, size: CVector2, color: CColor);
+    fn DrawRectangleRec(rec: CRectangle, color: CColor);
+    fn DrawRectanglePro(rec: CRectangle, origin: CVector2, rotation: c_float, color: CColor);
+    fn DrawRectangleGradientV(pos_x: c_int, pos_y: c_int, width: c_int, height: c_int, top: CColor, bottom: CColor);
+    fn DrawRectangleGradientH(pos_x: c_int, pos_y: c_int, width: c_int, height: c_int, left: CColor, right: CColor);
+    // Corners go counter-clockwise from the top-left, whatever the header calls them.
+    fn DrawRectangleGradientEx(rec: CRectangle, col1: CColor, col2: CColor, col3: CColor, col4: CColor);
+    fn DrawRectangleLines(pos_x: c_int, pos_y: c_int, width: c_int, height: c_int, color: CColor);
+    fn DrawRectangleLinesEx(rec: CRectangle, thickness: c_float, color: CColor);
+    fn DrawRectangleRounded(rec: CRectangle, roundness: c_float, segments: c_int, color: CColor);
+    fn DrawRectangleRoundedLines(rec: CRectangle, roundness: c_float, segments: c_int, color: CColor);
+    fn DrawRectangleRoundedLinesEx(rec: CRectangle, roundness: c_float, segments: c_int, thickness: c_float, color: CColor);
+    fn DrawTriangle(v1: CVector2, v2: CVector2, v3: CVector2, color: CColor);
+    fn DrawTriangleLines(v1: CVector2, v2: CVector2, v3: CVector2, color: CColor);
+    fn DrawTriangleFan(points: *const CVector2, point_count: c_int, color: CColor);
+    fn DrawTriangleStrip(points: *const CVector2, point_count: c_int, color: CColor);
+    fn DrawPoly(center: CVector2, sides: c_int, radius: c_float, rotation: c_float, color: CColor);
+    fn DrawPolyLines(center: CVector2, sides: c_int, radius: c_float, rotation: c_float, color: CColor);
+    fn DrawPolyLinesEx(center: CVector2, sides: c_int, radius: c_float, rotation: c_float, thickness: c_float, color: CColor);
+    fn DrawCircle3D(center: Vector3, radius: c_float, rotation_axis: Vector3, rotation_angle: c_float, color: CColor);
+
+    // Text.
+    fn DrawTextEx(font: CFont, text: *const i8, position: CVector2, font_size: c_float, spacing: c_float, tint: CColor);
+    fn DrawTextPro(font: CFont, text: *const i8, position: CVector2, origin: CVector2, rotation: c_float, font_size: c_float, spacing: c_float, tint: CColor);
+    fn DrawTextCodepoint(font: CFont, codepoint: c_int, position: CVector2, font_size: c_float, tint: CColor);
+    fn DrawTextCodepoints(font: CFont, codepoints: *const c_int, codepoint_count: c_int, position: CVector2, font_size: c_float, spacing: c_float, tint: CColor);
+    fn MeasureText(text: *const i8, font_size: c_int) -> c_int;
+    fn LoadFont(file_name: *const i8) -> CFont;
+    fn LoadFontEx(file_name: *const i8, font_size: c_int, codepoints: *const c_int, codepoint_count: c_int) -> CFont;
+    fn LoadFontFromImage(image: CImage, key: CColor, first_char: c_int) -> CFont;
+    fn LoadFontFromMemory(file_type: *const i8, file_data: *const u8, data_size: c_int, font_size: c_int, codepoints: *const c_int, codepoint_count: c_int) -> CFont;
+    fn IsFontValid(font: CFont) -> bool;
+    fn UnloadFont(font: CFont);
+    fn DrawTexture(texture: CTexture2D, pos_x: c_int, pos_y: c_int, tint: CColor);
+    fn DrawTextureEx(texture: CTexture2D, position: CVector2, rotation: c_float, scale: c_float, tint: CColor);
+    fn DrawTextureRec(texture: CTexture2D, source: CRectangle, position: CVector2, tint: CColor);
+    fn DrawTexturePro(texture: CTexture2D, source: CRectangle, dest: CRectangle, origin: CVector2, rotation: c_float, tint: CColor);
+
+    // Images and textures.
+    fn LoadImage(file_name: *const i8) -> CImage;
+    fn LoadImageFromMemory(file_type: *const i8, file_data: *const u8, data_size: c_int) -> CImage;
+    fn IsImageValid(image: CImage) -> bool;
+    fn UnloadImage(image: CImage);
+    fn LoadTexture(file_name: *const i8) -> CTexture2D;
+    fn LoadTextureFromImage(image: CImage) -> CTexture2D;
+    fn IsTextureValid(texture: CTexture2D) -> bool;
+    fn UnloadTexture(texture: CTexture2D);
+    fn GenImageColor(width: c_int, height: c_int, color: CColor) -> CImage;
+    fn ImageCopy(image: CImage) -> CImage;
+    fn ImageFromImage(image: CImage, rec: CRectangle) -> CImage;
+    fn GetImageColor(image: CImage, x: c_int, y: c_int) -> CColor;
+    fn ImageFormat(image: *mut CImage, new_format: c_int);
+    fn ImageCrop(image: *mut CImage, crop: CRectangle);
+    fn ImageAlphaCrop(image: *mut CImage, threshold: c_float);
+    fn ImageAlphaClear(image: *mut CImage, color: CColor, threshold: c_float);
+    fn ImageAlphaMask(image: *mut CImage, alpha_mask: CImage);
+    fn ImageAlphaPremultiply(image: *mut CImage);
+    fn ImageResize(image: *mut CImage, new_width: c_int, new_height: c_int);
+    fn ImageResizeNN(image: *mut CImage, new_width: c_int, new_height: c_int);
+    fn ImageResizeCanvas(image: *mut CImage, new_width: c_int, new_height: c_int, offset_x: c_int, offset_y: c_int, fill: CColor);
+    fn ImageFlipVertical(image: *mut CImage);
+    fn ImageFlipHorizontal(image: *mut CImage);
+    fn ImageRotate(image: *mut CImage, degrees: c_int);
+    fn ImageRotateCW(image: *mut CImage);
+    fn ImageRotateCCW(image: *mut CImage);
+    fn ImageColorTint(image: *mut CImage, color: CColor);
+    fn ImageColorInvert(image: *mut CImage);
+    fn ImageColorGrayscale(image: *mut CImage);
+    fn ImageColorContrast(image: *mut CImage, contrast: c_float);
+    fn ImageColorBrightness(image: *mut CImage, brightness: c_int);
+    fn ImageColorReplace(image: *mut CImage, color: CColor, replace: CColor);
+    fn ImageClearBackground(dst: *mut CImage, color: CColor);
+    fn ImageDrawPixel(dst: *mut CImage, pos_x: c_int, pos_y: c_int, color: CColor);
+    fn ImageDrawLine(dst: *mut CImage, start_pos_x: c_int, start_pos_y: c_int, end_pos_x: c_int, end_pos_y: c_int, color: CColor);
+    fn ImageDrawLineEx(dst: *mut CImage, start: CVector2, end: CVector2, thick: c_int, color: CColor);
+    fn ImageDrawCircle(dst: *mut CImage, center_x: c_int, center_y: c_int, radius: c_int, color: CColor);
+    fn ImageDrawCircleLines(dst: *mut CImage, center_x: c_int, center_y: c_int, radius: c_int, color: CColor);
+    fn ImageDrawRectangle(dst: *mut CImage, pos_x: c_int, pos_y: c_int, width: c_int, height: c_int, color: CColor);
+    fn ImageDrawRectangleRec(dst: *mut CImage, rec: CRectangle, color: CColor);
+    fn ImageDrawRectangleLines(dst: *mut CImage, rec: CRectangle, thick: c_int, color: CColor);
+    fn ImageDrawTriangle(dst: *mut CImage, v1: CVector2, v2: CVector2, v3: CVector2, color: CColor);
+    fn ImageDraw(dst: *mut CImage, src: CImage, src_rec: CRectangle, dst_rec: CRectangle, tint: CColor);
+    fn ExportImage(image: CImage, file_name: *const i8) -> bool;
+    fn ExportImageToMemory(image: CImage, file_type: *const i8, file_size: *mut c_int) -> *mut u8;
+    fn LoadImageFromScreen() -> CImage;
+    fn TakeScreenshot(file_name: *const i8);
+    fn MemFree(ptr: *mut c_void);
+    fn LoadRenderTexture(width: c_int, height: c_int) -> CRenderTexture2D;
+    fn IsRenderTextureValid(target: CRenderTexture2D) -> bool;
+    fn UnloadRenderTexture(target: CRenderTexture2D);
+    fn BeginTextureMode(target: CRenderTexture2D);
+    fn EndTextureMode();
+    fn ImageDrawTextEx(dst: *mut CImage, font: CFont, text: *const i8, position: CVector2, font_size: c_float, spacing: c_float, tint: CColor);
+
+    // Cameras.
+    fn BeginMode2D(camera: CCamera2D);
+    fn EndMode2D();
+    fn GetScreenToWorld2D(position: CVector2, camera: CCamera2D) -> CVector2;
+    fn GetWorldToScreen2D(position: CVector2, camera: CCamera2D) -> CVector2;
+    fn BeginMode3D(camera: CCamera3D);
+    fn EndMode3D();
+    fn UpdateCamera(camera: *mut CCamera3D, mode: c_int);
+    fn GetScreenToWorldRay(position: CVector2, camera: CCamera3D) -> Ray;
+    fn GetWorldToScreen(position: Vector3, camera: CCamera3D) -> CVector2;
+
+    // 3D drawing.
+    fn DrawLine3D(start_pos: Vector3, end_pos: Vector3, color: CColor);
+    fn DrawPoint3D(position: Vector3, color: CColor);
+    fn DrawTriangle3D(v1: Vector3, v2: Vector3, v3: Vector3, color: CColor);
+    fn DrawCube(position: Vector3, width: c_float, height: c_float, length: c_float, color: CColor);
+    fn DrawCubeV(position: Vector3, size: Vector3, color: CColor);
+    fn DrawCubeWires(position: Vector3, width: c_float, height: c_float, length: c_float, color: CColor);
+    fn DrawCubeWiresV(position: Vector3, size: Vector3, color: CColor);
+    fn DrawSphere(center_pos: Vector3, radius: c_float, color: CColor);
+    fn DrawSphereEx(center_pos: Vector3, radius: c_float, rings: c_int, slices: c_int, color: CColor);
+    fn DrawSphereWires(center_pos: Vector3, radius: c_float, rings: c_int, slices: c_int, color: CColor);
+    fn DrawCylinder(position: Vector3, radius_top: c_float, radius_bottom: c_float, height: c_float, slices: c_int, color: CColor);
+    fn DrawCylinderEx(start_pos: Vector3, end_pos: Vector3, start_radius: c_float, end_radius: c_float, sides: c_int, color: CColor);
+    fn DrawCylinderWires(position: Vector3, radius_top: c_float, radius_bottom: c_float, height: c_float, slices: c_int, color: CColor);
+    fn DrawCylinderWiresEx(start_pos: Vector3, end_pos: Vector3, start_radius: c_float, end_radius: c_float, sides: c_int, color: CColor);
+    fn DrawCapsule(start_pos: Vector3, end_pos: Vector3, radius: c_float, slices: c_int, rings: c_int, color: CColor);
+    fn DrawCapsuleWires(start_pos: Vector3, end_pos: Vector3, radius: c_float, slices: c_int, rings: c_int, color: CColor);
+    fn DrawPlane(center_pos: Vector3, size: CVector2, color: CColor);
+    fn DrawRay(ray: Ray, color: CColor);
+    fn DrawGrid(slices: c_int, spacing: c_float);
+    fn DrawBoundingBox(bbox: BoundingBox, color: CColor);
+    fn DrawBillboard(camera: CCamera3D, texture: CTexture2D, position: Vector3, scale: c_float, tint: CColor);
+    fn DrawBillboardRec(camera: CCamera3D, texture: CTexture2D, source: CRectangle, position: Vector3, size: CVector2, tint: CColor);
+    fn DrawBillboardPro(camera: CCamera3D, texture: CTexture2D, source: CRectangle, position: Vector3, up: Vector3, size: CVector2, origin: CVector2, rotation: c_float, tint: CColor);
+
+    // Splines.
+    fn DrawSplineLinear(points: *const CVector2, point_count: c_int, thickness: c_float, color: CColor);
+    fn DrawSplineBasis(points: *const CVector2, point_count: c_int, thickness: c_float, color: CColor);
+    fn DrawSplineCatmullRom(points: *const CVector2, point_count: c_int, thickness: c_float, color: CColor);
+    fn DrawSplineBezierQuadratic(points: *const CVector2, point_count: c_int, thickness: c_float, color: CColor);
+    fn DrawSplineBezierCubic(points: *const CVector2, point_count: c_int, thickness: c_float, color: CColor);
+
+    // 2D collisions.
+    fn CheckCollisionRecs(rec1: CRectangle, rec2: CRectangle) -> bool;
+    fn CheckCollisionCircles(center1: CVector2, radius1: c_float, center2: CVector2, radius2: c_float) -> bool;
+    fn CheckCollisionCircleRec(center: CVector2, radius: c_float, rec: CRectangle) -> bool;
+    fn CheckCollisionCircleLine(center: CVector2, radius: c_float, p1: CVector2, p2: CVector2) -> bool;
+    fn CheckCollisionPointRec(point: CVector2, rec: CRectangle) -> bool;
+    fn CheckCollisionPointCircle(point: CVector2, center: CVector2, radius: c_float) -> bool;
+    fn CheckCollisionPointTriangle(point: CVector2, p1: CVector2, p2: CVector2, p3: CVector2) -> bool;
+    fn CheckCollisionPointLine(point: CVector2, p1: CVector2, p2: CVector2, threshold: c_int) -> bool;
+    fn CheckCollisionPointPoly(point: CVector2, points: *const CVector2, point_count: c_int) -> bool;
+    fn CheckCollisionLines(start_pos1: CVector2, end_pos1: CVector2, start_pos2: CVector2, end_pos2: CVector2, collision_point: *mut CVector2) -> bool;
+    fn GetCollisionRec(rec1: CRectangle, rec2: CRectangle) -> CRectangle;
+
+    // Models.
+    fn LoadModel(file_name: *const i8) -> CModel;
+    fn LoadModelFromMesh(mesh: CMesh) -> CModel;
+    fn IsModelValid(model: CModel) -> bool;
+    fn UnloadModel(model: CModel);
+    fn GetModelBoundingBox(model: CModel) -> BoundingBox;
+    fn SetModelMeshMaterial(model: *mut CModel, mesh_id: c_int, material_id: c_int);
+    fn DrawModel(model: CModel, position: Vector3, scale: c_float, tint: CColor);
+    fn DrawModelEx(model: CModel, position: Vector3, rotation_axis: Vector3, rotation_angle: c_float, scale: Vector3, tint: CColor);
+    fn DrawModelWires(model: CModel, position: Vector3, scale: c_float, tint: CColor);
+    fn DrawModelWiresEx(model: CModel, position: Vector3, rotation_axis: Vector3, rotation_angle: c_float, scale: Vector3, tint: CColor);
+    fn DrawMesh(mesh: CMesh, material: CMaterial, transform: Matrix);
+    fn UnloadMesh(mesh: CMesh);
+    fn GetMeshBoundingBox(mesh: CMesh) -> BoundingBox;
+    fn GenMeshTangents(mesh: *mut CMesh);
+    fn ExportMesh(mesh: CMesh, file_name: *const i8) -> bool;
+    fn GenMeshPoly(sides: c_int, radius: c_float) -> CMesh;
+    fn GenMeshPlane(width: c_float, length: c_float, res_x: c_int, res_z: c_int) -> CMesh;
+    fn GenMeshCube(width: c_float, height: c_float, length: c_float) -> CMesh;
+    fn GenMeshSphere(radius: c_float, rings: c_int, slices: c_int) -> CMesh;
+    fn GenMeshHemiSphere(radius: c_float, rings: c_int, slices: c_int) -> CMesh;
+    fn GenMeshCylinder(radius: c_float, height: c_float, slices: c_int) -> CMesh;
+    fn GenMeshCone(radius: c_float, height: c_float, slices: c_int) -> CMesh;
+    fn GenMeshTorus(radius: c_float, size: c_float, rad_seg: c_int, sides: c_int) -> CMesh;
+    fn GenMeshKnot(radius: c_float, size: c_float, rad_seg: c_int, sides: c_int) -> CMesh;
+    fn GenMeshHeightmap(heightmap: CImage, size: Vector3) -> CMesh;
+    fn GenMeshCubicmap(cubicmap: CImage, cube_size: Vector3) -> CMesh;
+    fn LoadMaterials(file_name: *const i8, material_count: *mut c_int) -> *mut CMaterial;
+    fn LoadMaterialDefault() -> CMaterial;
+    fn IsMaterialValid(material: CMaterial) -> bool;
+    fn UnloadMaterial(material: CMaterial);
+    fn LoadModelAnimations(file_name: *const i8, anim_count: *mut c_int) -> *mut CModelAnimation;
+    fn UpdateModelAnimation(model: CModel, anim: CModelAnimation, frame: c_int);
+    fn UnloadModelAnimation(anim: CModelAnimation);
+    fn IsModelAnimationValid(model: CModel, anim: CModelAnimation) -> bool;
+    fn rlGetTextureIdDefault() -> c_uint;
+
+    // 3D collisions.
+    fn CheckCollisionSpheres(center1: Vector3, radius1: c_float, center2: Vector3, radius2: c_float) -> bool;
+    fn CheckCollisionBoxes(box1: BoundingBox, box2: BoundingBox) -> bool;
+    fn CheckCollisionBoxSphere(bbox: BoundingBox, center: Vector3, radius: c_float) -> bool;
+    fn GetRayCollisionSphere(ray: Ray, center: Vector3, radius: c_float) -> CRayCollision;
+    fn GetRayCollisionBox(ray: Ray, bbox: BoundingBox) -> CRayCollision;
+    fn GetRayCollisionMesh(ray: Ray, mesh: CMesh, transform: Matrix) -> CRayCollision;
+    fn GetRayCollisionTriangle(ray: Ray, p1: Vector3, p2: Vector3, p3: Vector3) -> CRayCollision;
+    fn GetRayCollisionQuad(ray: Ray, p1: Vector3, p2: Vector3, p3: Vector3, p4: Vector3) -> CRayCollision;
+
+    // Shaders.
+    fn LoadShaderFromMemory(vs_code: *const i8, fs_code: *const i8) -> CShader;
+    fn UnloadShader(shader: CShader);
+    fn GetShaderLocation(shader: CShader, uniform_name: *const i8) -> c_int;
+    fn SetShaderValue(shader: CShader, loc_index: c_int, value: *const c_void, uniform_type: c_int);
+    fn SetShaderValueMatrix(shader: CShader, loc_index: c_int, mat: Matrix);
+    fn SetShaderValueTexture(shader: CShader, loc_index: c_int, texture: CTexture2D);
+    fn BeginShaderMode(shader: CShader);
+    fn EndShaderMode();
+    fn rlGetShaderIdDefault() -> c_uint;
+
+    // Blending and scissoring.
+    fn BeginBlendMode(mode: c_int);
+    fn EndBlendMode();
+    fn rlSetBlendFactors(gl_src_factor: c_int, gl_dst_factor: c_int, gl_equation: c_int);
+    fn rlSetBlendFactorsSeparate(gl_src_rgb: c_int, gl_dst_rgb: c_int, gl_src_alpha: c_int, gl_dst_alpha: c_int, gl_eq_rgb: c_int, gl_eq_alpha: c_int);
+    fn BeginScissorMode(x: c_int, y: c_int, width: c_int, height: c_int);
+    fn EndScissorMode();
+
+    // Audio.
+    fn InitAudioDevice();
+    fn CloseAudioDevice();
+    fn IsAudioDeviceReady() -> bool;
+    fn SetMasterVolume(volume: c_float);
+    fn GetMasterVolume() -> c_float;
+    fn LoadWave(file_name: *const i8) -> CWave;
+    fn LoadWaveFromMemory(file_type: *const i8, file_data: *const u8, data_size: c_int) -> CWave;
+    fn IsWaveValid(wave: CWave) -> bool;
+    fn WaveCopy(wave: CWave) -> CWave;
+    fn WaveCrop(wave: *mut CWave, init_frame: c_int, final_frame: c_int);
+    fn WaveFormat(wave: *mut CWave, sample_rate: c_int, sample_size: c_int, channels: c_int);
+    fn LoadWaveSamples(wave: CWave) -> *mut c_float;
+    fn UnloadWaveSamples(samples: *mut c_float);
+    fn ExportWave(wave: CWave, file_name: *const i8) -> bool;
+    fn ExportWaveAsCode(wave: CWave, file_name: *const i8) -> bool;
+    fn UnloadWave(wave: CWave);
+    fn LoadSound(file_name: *const i8) -> CSound;
+    fn LoadSoundFromWave(wave: CWave) -> CSound;
+    fn LoadSoundAlias(source: CSound) -> CSound;
+    fn IsSoundValid(sound: CSound) -> bool;
+    fn UnloadSound(sound: CSound);
+    fn UnloadSoundAlias(alias: CSound);
+    fn PlaySound(sound: CSound);
+    fn StopSound(sound: CSound);
+    fn PauseSound(sound: CSound);
+    fn ResumeSound(sound: CSound);
+    fn IsSoundPlaying(sound: CSound) -> bool;
+    fn SetSoundVolume(sound: CSound, volume: c_float);
+    fn SetSoundPitch(sound: CSound, pitch: c_float);
+    fn SetSoundPan(sound: CSound, pan: c_float);
+
+    // Streaming audio.
+    fn LoadMusicStream(file_name: *const i8) -> CMusic;
+    fn LoadMusicStreamFromMemory(file_type: *const i8, data: *const u8, data_size: c_int) -> CMusic;
+    fn IsMusicValid(music: CMusic) -> bool;
+    fn UnloadMusicStream(music: CMusic);
+    fn PlayMusicStream(music: CMusic);
+    fn IsMusicStreamPlaying(music: CMusic) -> bool;
+    fn UpdateMusicStream(music: CMusic);
+    fn StopMusicStream(music: CMusic);
+    fn PauseMusicStream(music: CMusic);
+    fn ResumeMusicStream(music: CMusic);
+    fn SeekMusicStream(music: CMusic, position: c_float);
+    fn SetMusicVolume(music: CMusic, volume: c_float);
+    fn SetMusicPitch(music: CMusic, pitch: c_float);
+    fn SetMusicPan(music: CMusic, pan: c_float);
+    fn GetMusicTimeLength(music: CMusic) -> c_float;
+    fn GetMusicTimePlayed(music: CMusic) -> c_float;
+    fn LoadAudioStream(sample_rate: c_uint, sample_size: c_uint, channels: c_uint) -> CAudioStream;
+    fn IsAudioStreamValid(stream: CAudioStream) -> bool;
+    fn UnloadAudioStream(stream: CAudioStream);
+    fn UpdateAudioStream(stream: CAudioStream, data: *const c_void, frame_count: c_int);
+    fn IsAudioStreamProcessed(stream: CAudioStream) -> bool;
+    fn PlayAudioStream(stream: CAudioStream);
+    fn PauseAudioStream(stream: CAudioStream);
+    fn ResumeAudioStream(stream: CAudioStream);
+    fn IsAudioStreamPlaying(stream: CAudioStream) -> bool;
+    fn StopAudioStream(stream: CAudioStream);
+    fn SetAudioStreamVolume(stream: CAudioStream, volume: c_float);
+    fn SetAudioStreamPitch(stream: CAudioStream, pitch: c_float);
+    fn SetAudioStreamPan(stream: CAudioStream, pan: c_float);
+    fn SetAudioStreamCallback(stream: CAudioStream, callback: Option<CAudioCallback>);
+    fn AttachAudioMixedProcessor(processor: CAudioCallback);
+    fn DetachAudioMixedProcessor(processor: CAudioCallback);
 }
 
 /*==========================================
              The public interface.
   ==========================================*/
 
-#[derive(Clone, Copy)]
+#[derive(Debug)]
+pub enum Error {
+    InvalidString(NulError),        // A string passed to raylib contained an interior NUL byte.
+    InvalidSize(i32, i32),          // A width or height was negative.
+    WindowAlreadyOpen,
+    WindowInitFailed,
+    ResourceLoadFailed(String),     // Holds the path (or file type, for in-memory loads) of the resource.
+    ResourceExportFailed(String),   // Likewise.
+    InvalidAnimation(String),       // Holds the animation's name; it doesn't fit the model's skeleton, or has no frames.
+    InvalidAudioFormat(u32, u32, u32),  // Sample rate, sample size and channels; see Wave::format.
+    AudioDeviceAlreadyOpen,
+    AudioDeviceFailed,
+    TooManyAudioCallbacks,          // Every callback slot is taken; see AUDIO_CALLBACK_SLOTS.
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::InvalidString(e) => write!(f, "invalid string: {}", e),
+            Error::InvalidSize(width, height) => write!(f, "invalid size {}x{}: width and height should be non-negative", width, height),
+            Error::WindowAlreadyOpen => write!(f, "a window is already open; raylib only supports one"),
+            Error::WindowInitFailed => write!(f, "failed to initialize the window"),
+            Error::ResourceLoadFailed(path) => write!(f, "failed to load resource: {}", path),
+            Error::ResourceExportFailed(path) => write!(f, "failed to export resource: {}", path),
+            Error::InvalidAnimation(name) => write!(f, "animation {:?} doesn't match the model, or has no frames", name),
+            Error::InvalidAudioFormat(sample_rate, sample_size, channels) => write!(f, "invalid audio format: {} Hz, {}-bit, {} channels", sample_rate, sample_size, channels),
+            Error::AudioDeviceAlreadyOpen => write!(f, "an audio device is already open; raylib only supports one"),
+            Error::AudioDeviceFailed => write!(f, "failed to initialize the audio device"),
+            Error::TooManyAudioCallbacks => write!(f, "too many audio callbacks; at most {} may be set at once", AUDIO_CALLBACK_SLOTS),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::InvalidString(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<NulError> for Error {
+    fn from(e: NulError) -> Error {
+        return Error::InvalidString(e);
+    }
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct Color {
     pub r: u8,
     pub g: u8,
@@ -154,7 +718,8 @@ pub mod colors {
     pub static RAY_WHITE: Color   = Color {r: 245, g: 245, b: 245, a: 255};
 }
 
-#[derive(Clone, Copy, Debug)]
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
 pub struct Vector2 {
     pub x: f32,
     pub y: f32,
@@ -168,6 +733,13 @@ impl Vector2 {
         }
     }
 
+    pub const ZERO: Vector2 = Vector2 { x: 0.0, y: 0.0 };
+    pub const ONE: Vector2 = Vector2 { x: 1.0, y: 1.0 };
+
+    pub fn new(x: f32, y: f32) -> Vector2 {
+        return Vector2 { x, y };
+    }
+
     pub fn from_angle_and_len(angle: f32, len: f32) -> Vector2 {
         return Vector2 {
             x: len * f32::cos(angle),
@@ -179,27 +751,48 @@ impl Vector2 {
         return f32::sqrt(self.x * self.x + self.y * self.y);
     }
 
-    pub fn normalize(&mut self) -> Vector2 {
-        let len = self.len();
-        self.x /= len;
-        self.y /= len;
+    pub fn len_sqr(&self) -> f32 {
+        return self.x * self.x + self.y * self.y;
+    }
 
-        return Vector2 {x: self.x, y: self.y};
+    pub fn distance(&self, rhs: Vector2) -> f32 {
+        return (rhs - *self).len();
     }
 
-    pub fn rotate(&mut self, angle: f32) -> Vector2 {
-        let cosres = f32::cos(angle);
-        let sinres = f32::sin(angle);
+    pub fn distance_sqr(&self, rhs: Vector2) -> f32 {
+        return (rhs - *self).len_sqr();
+    }
+
+    // Normalizes in place. The zero vector is left as is.
+    pub fn normalize(&mut self) {
+        *self = self.normalized();
+    }
+
+    #[must_use]
+    pub fn normalized(&self) -> Vector2 {
+        let len = self.len();
+
+        if len == 0.0 {
+            return *self;
+        }
 
-        let mut result = Vector2 {x:0.0,y:0.0};
+        return Vector2 {x: self.x / len, y: self.y / len};
+    }
 
-        result.x = self.x*cosres - self.y*sinres;
-        result.y = self.x*sinres + self.y*cosres;
+    // Rotates in place, by angle radians.
+    pub fn rotate(&mut self, angle: f32) {
+        *self = self.rotated(angle);
+    }
 
-        self.x = result.x;
-        self.y = result.y;
+    #[must_use]
+    pub fn rotated(&self, angle: f32) -> Vector2 {
+        let cosres = f32::cos(angle);
+        let sinres = f32::sin(angle);
 
-        return Vector2 {x: self.x, y: self.y};
+        return Vector2 {
+            x: self.x*cosres - self.y*sinres,
+            y: self.x*sinres + self.y*cosres,
+        };
     }
 
     pub fn dot(&self, rhs: Vector2) -> f32 {
@@ -224,6 +817,147 @@ impl Vector2 {
 
         return result;
     }
+
+    // Treats this as a point on the z = 0 plane.
+    #[must_use]
+    pub fn transform(&self, mat: Matrix) -> Vector2 {
+        return Vector2 {
+            x: mat.m0 * self.x + mat.m4 * self.y + mat.m12,
+            y: mat.m1 * self.x + mat.m5 * self.y + mat.m13,
+        };
+    }
+
+    // Angle from the positive x axis, in radians.
+    pub fn angle(&self) -> f32 {
+        return f32::atan2(self.y, self.x);
+    }
+
+    // Rotated 90 degrees counter-clockwise (in a y-up space).
+    #[must_use]
+    pub fn perpendicular(&self) -> Vector2 {
+        return Vector2 { x: -self.y, y: self.x };
+    }
+
+    #[must_use]
+    pub fn lerp(&self, rhs: Vector2, amount: f32) -> Vector2 {
+        return Vector2 {
+            x: self.x + amount * (rhs.x - self.x),
+            y: self.y + amount * (rhs.y - self.y),
+        };
+    }
+
+    // normal should be normalized.
+    #[must_use]
+    pub fn reflect(&self, normal: Vector2) -> Vector2 {
+        return *self - normal * (2.0 * self.dot(normal));
+    }
+
+    // n should be normalized. r is the ratio of the refractive indices, from over to.
+    #[must_use]
+    pub fn refract(&self, n: Vector2, r: f32) -> Vector2 {
+        let dot = self.dot(n);
+        let d = 1.0 - r * r * (1.0 - dot * dot);
+
+        if d < 0.0 {
+            return Vector2::ZERO;
+        }
+
+        return *self * r - n * (r * dot + f32::sqrt(d));
+    }
+
+    // The component of this vector along onto.
+    #[must_use]
+    pub fn project(&self, onto: Vector2) -> Vector2 {
+        let len_sqr = onto.len_sqr();
+
+        if len_sqr == 0.0 {
+            return Vector2::ZERO;
+        }
+
+        return onto * (self.dot(onto) / len_sqr);
+    }
+
+    // The component of this vector perpendicular to onto.
+    #[must_use]
+    pub fn reject(&self, onto: Vector2) -> Vector2 {
+        return *self - self.project(onto);
+    }
+
+    #[must_use]
+    pub fn invert(&self) -> Vector2 {
+        return Vector2 { x: 1.0 / self.x, y: 1.0 / self.y };
+    }
+
+    #[must_use]
+    pub fn min(&self, rhs: Vector2) -> Vector2 {
+        return Vector2 { x: f32::min(self.x, rhs.x), y: f32::min(self.y, rhs.y) };
+    }
+
+    #[must_use]
+    pub fn max(&self, rhs: Vector2) -> Vector2 {
+        return Vector2 { x: f32::max(self.x, rhs.x), y: f32::max(self.y, rhs.y) };
+    }
+
+    // Componentwise.
+    #[must_use]
+    pub fn clamp(&self, min: Vector2, max: Vector2) -> Vector2 {
+        return Vector2 {
+            x: f32::min(max.x, f32::max(min.x, self.x)),
+            y: f32::min(max.y, f32::max(min.y, self.y)),
+        };
+    }
+
+    // Clamps the length, keeping the direction.
+    #[must_use]
+    pub fn clamp_len(&self, min: f32, max: f32) -> Vector2 {
+        let len = self.len();
+
+        if len == 0.0 {
+            return *self;
+        }
+
+        if len < min {
+            return *self * (min / len);
+        }
+
+        if len > max {
+            return *self * (max / len);
+        }
+
+        return *self;
+    }
+
+    // Steps towards target by at most max_distance, without overshooting.
+    #[must_use]
+    pub fn move_towards(&self, target: Vector2, max_distance: f32) -> Vector2 {
+        let delta = target - *self;
+        let dist = delta.len();
+
+        if dist == 0.0 || (max_distance >= 0.0 && dist <= max_distance) {
+            return target;
+        }
+
+        return *self + delta * (max_distance / dist);
+    }
+
+    // Same tolerance as raymath's Vector2Equals, relative for large values.
+    pub fn approx_eq(&self, rhs: Vector2) -> bool {
+        return approx_eq_f32(self.x, rhs.x) && approx_eq_f32(self.y, rhs.y);
+    }
+}
+
+const EPSILON: f32 = 0.000001;
+
+fn approx_eq_f32(a: f32, b: f32) -> bool {
+    return f32::abs(a - b) <= EPSILON * f32::max(1.0, f32::max(f32::abs(a), f32::abs(b)));
+}
+
+impl Neg for Vector2 {
+    type Output = Vector2;
+
+    fn neg(self) -> Vector2 {
+        return Vector2 {x: -self.x, y: -self.y};
+    }
 }
 
 impl AddAssign for Vector2 {
@@ -253,6 +987,15 @@ impl SubAssign for Vector2 {
     }
 }
 
+impl SubAssign<f32> for Vector2 {
+    fn sub_assign(&mut self, rhs: f32) {
+        *self = Self {
+            x: self.x - rhs,
+            y: self.y - rhs,
+        };
+    }
+}
+
 impl MulAssign for Vector2 {
     fn mul_assign(&mut self, rhs: Self) {
         *self = Self {
@@ -280,6 +1023,15 @@ impl DivAssign for Vector2 {
     }
 }
 
+impl DivAssign<f32> for Vector2 {
+    fn div_assign(&mut self, rhs: f32) {
+        *self = Self {
+            x: self.x / rhs,
+            y: self.y / rhs,
+        };
+    }
+}
+
 impl Add<Vector2> for Vector2 {
     type Output = Vector2;
 
@@ -296,6 +1048,22 @@ impl Sub<Vector2> for Vector2 {
     }
 }
 
+impl Add<f32> for Vector2 {
+    type Output = Vector2;
+
+    fn add(self, rhs: f32) -> Vector2 {
+        return Vector2 {x: self.x + rhs, y: self.y + rhs};
+    }
+}
+
+impl Sub<f32> for Vector2 {
+    type Output = Vector2;
+
+    fn sub(self, rhs: f32) -> Vector2 {
+        return Vector2 {x: self.x - rhs, y: self.y - rhs};
+    }
+}
+
 impl Mul<f32> for Vector2 {
     type Output = Vector2;
 
@@ -304,6 +1072,22 @@ impl Mul<f32> for Vector2 {
     }
 }
 
+impl Mul<Vector2> for f32 {
+    type Output = Vector2;
+
+    fn mul(self, rhs: Vector2) -> Vector2 {
+        return Vector2 {x: self * rhs.x, y: self * rhs.y};
+    }
+}
+
+impl Mul<Vector2> for Vector2 {
+    type Output = Vector2;
+
+    fn mul(self, rhs: Vector2) -> Vector2 {
+        return Vector2 {x: self.x * rhs.x, y: self.y * rhs.y};
+    }
+}
+
 impl Div<f32> for Vector2 {
     type Output = Vector2;
 
@@ -320,287 +1104,2553 @@ impl Div<Vector2> for Vector2 {
     }
 }
 
-pub struct WindowContext {}
+/*===================================
+              3D math.
+=====================================*/
 
-impl WindowContext {
-    pub fn window_should_close(&self) -> bool {
-        let flag;
+// The 3D math types share raylib's layout, so they go across the FFI boundary as is.
 
-        unsafe {
-            flag = WindowShouldClose();
-        }
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Vector3 {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+}
 
-        return flag;
+impl Vector3 {
+    pub const ZERO: Vector3 = Vector3 { x: 0.0, y: 0.0, z: 0.0 };
+    pub const ONE: Vector3 = Vector3 { x: 1.0, y: 1.0, z: 1.0 };
+    pub const UP: Vector3 = Vector3 { x: 0.0, y: 1.0, z: 0.0 };
+
+    pub fn new(x: f32, y: f32, z: f32) -> Vector3 {
+        return Vector3 { x, y, z };
     }
 
-    pub fn init_drawing_context(&self) -> DrawingContext {
-        unsafe {
-            BeginDrawing();
-        }
+    pub fn len(&self) -> f32 {
+        return f32::sqrt(self.len_sqr());
+    }
 
-        return DrawingContext {};
+    pub fn len_sqr(&self) -> f32 {
+        return self.x * self.x + self.y * self.y + self.z * self.z;
     }
-}
 
-impl Drop for WindowContext {
-    fn drop(&mut self) {
-        unsafe{
-            CloseWindow();
-        }
+    pub fn distance(&self, rhs: Vector3) -> f32 {
+        return (rhs - *self).len();
     }
-}
 
-pub struct DrawingContext {}
+    pub fn distance_sqr(&self, rhs: Vector3) -> f32 {
+        return (rhs - *self).len_sqr();
+    }
 
-impl DrawingContext {
-    pub fn clear_background(&mut self, color: Color) {
-        unsafe {
-            ClearBackground(color.to_ccolor());
-        }
+    pub fn dot(&self, rhs: Vector3) -> f32 {
+        return self.x * rhs.x + self.y * rhs.y + self.z * rhs.z;
     }
 
-    pub fn draw_text(&mut self, text: &str, pos_x: i32, pos_y: i32, font_size: i32, color: Color) {
-        unsafe {
-            let converted_text = CString::new(text).expect("Failed to create CString.");
-            let text_pointer = converted_text.as_ptr();
+    #[must_use]
+    pub fn cross(&self, rhs: Vector3) -> Vector3 {
+        return Vector3 {
+            x: self.y * rhs.z - self.z * rhs.y,
+            y: self.z * rhs.x - self.x * rhs.z,
+            z: self.x * rhs.y - self.y * rhs.x,
+        };
+    }
 
-            let converted_color = color.to_ccolor();
+    // Some vector perpendicular to this one, picked by crossing with the most orthogonal axis.
+    #[must_use]
+    pub fn perpendicular(&self) -> Vector3 {
+        let mut min = f32::abs(self.x);
+        let mut cardinal_axis = Vector3 { x: 1.0, y: 0.0, z: 0.0 };
 
-            DrawText(text_pointer, pos_x as c_int, pos_y as c_int, font_size as c_int, converted_color);
+        if f32::abs(self.y) < min {
+            min = f32::abs(self.y);
+            cardinal_axis = Vector3 { x: 0.0, y: 1.0, z: 0.0 };
         }
+
+        if f32::abs(self.z) < min {
+            cardinal_axis = Vector3 { x: 0.0, y: 0.0, z: 1.0 };
+        }
+
+        return self.cross(cardinal_axis);
     }
 
-    pub fn draw_rectangle(&mut self, pos_x: i32, pos_y: i32, width: i32, height: i32, color: Color) {
-        let converted_color = color.to_ccolor();
+    // Unsigned angle between the two vectors, in radians.
+    pub fn angle_with(&self, rhs: Vector3) -> f32 {
+        let len = self.cross(rhs).len();
+        let dot = self.dot(rhs);
 
-        unsafe {
-            DrawRectangle(pos_x as c_int, pos_y as c_int, width as c_int, height as c_int, converted_color);
+        return f32::atan2(len, dot);
+    }
+
+    // Normalizes in place. The zero vector is left as is.
+    pub fn normalize(&mut self) {
+        *self = self.normalized();
+    }
+
+    #[must_use]
+    pub fn normalized(&self) -> Vector3 {
+        let len = self.len();
+
+        if len == 0.0 {
+            return *self;
         }
+
+        return *self / len;
     }
 
-    pub fn draw_circle(&mut self, center_x: i32, center_y: i32, radius: f32, color: Color) {
-        let converted_color = color.to_ccolor();
+    // Makes both vectors normalized and orthogonal to each other (Gram-Schmidt).
+    pub fn ortho_normalize(v1: &mut Vector3, v2: &mut Vector3) {
+        *v1 = v1.normalized();
+        let vn1 = v1.cross(*v2).normalized();
+        *v2 = vn1.cross(*v1);
+    }
 
-        unsafe {
-            DrawCircle(center_x as c_int, center_y as c_int, radius as c_float, converted_color);
+    #[must_use]
+    pub fn project(&self, onto: Vector3) -> Vector3 {
+        let len_sqr = onto.len_sqr();
+
+        if len_sqr == 0.0 {
+            return Vector3::ZERO;
         }
+
+        return onto * (self.dot(onto) / len_sqr);
     }
 
-    pub fn draw_line_ex(&mut self, start_pos: Vector2, end_pos: Vector2, thickness: f32, color: Color) {
-        let color = color.to_ccolor();
-        let start_pos = start_pos.to_cvector2();
-        let end_pos = end_pos.to_cvector2();
-        
-        unsafe {
-            DrawLineEx(start_pos, end_pos, thickness as c_float, color);
+    #[must_use]
+    pub fn reject(&self, onto: Vector3) -> Vector3 {
+        return *self - self.project(onto);
+    }
+
+    #[must_use]
+    pub fn transform(&self, mat: Matrix) -> Vector3 {
+        return Vector3 {
+            x: mat.m0 * self.x + mat.m4 * self.y + mat.m8 * self.z + mat.m12,
+            y: mat.m1 * self.x + mat.m5 * self.y + mat.m9 * self.z + mat.m13,
+            z: mat.m2 * self.x + mat.m6 * self.y + mat.m10 * self.z + mat.m14,
+        };
+    }
+
+    #[must_use]
+    pub fn rotate_by_quaternion(&self, q: Quaternion) -> Vector3 {
+        let v = self;
+
+        return Vector3 {
+            x: v.x * (q.x * q.x + q.w * q.w - q.y * q.y - q.z * q.z) + v.y * (2.0 * q.x * q.y - 2.0 * q.w * q.z) + v.z * (2.0 * q.x * q.z + 2.0 * q.w * q.y),
+            y: v.x * (2.0 * q.w * q.z + 2.0 * q.x * q.y) + v.y * (q.w * q.w - q.x * q.x + q.y * q.y - q.z * q.z) + v.z * (-2.0 * q.w * q.x + 2.0 * q.y * q.z),
+            z: v.x * (-2.0 * q.w * q.y + 2.0 * q.x * q.z) + v.y * (2.0 * q.w * q.x + 2.0 * q.y * q.z) + v.z * (q.w * q.w - q.x * q.x - q.y * q.y + q.z * q.z),
+        };
+    }
+
+    // Angle in radians, counter-clockwise looking down axis.
+    #[must_use]
+    pub fn rotate_by_axis_angle(&self, axis: Vector3, angle: f32) -> Vector3 {
+        let axis = axis.normalized();
+        let half_angle = angle / 2.0;
+
+        let a = f32::cos(half_angle);
+        let w = axis * f32::sin(half_angle);
+
+        let wv = w.cross(*self);
+        let wwv = w.cross(wv);
+
+        return *self + wv * (2.0 * a) + wwv * 2.0;
+    }
+
+    #[must_use]
+    pub fn lerp(&self, rhs: Vector3, amount: f32) -> Vector3 {
+        return *self + (rhs - *self) * amount;
+    }
+
+    // normal should be normalized.
+    #[must_use]
+    pub fn reflect(&self, normal: Vector3) -> Vector3 {
+        return *self - normal * (2.0 * self.dot(normal));
+    }
+
+    // n should be normalized. r is the ratio of the refractive indices, from over to.
+    #[must_use]
+    pub fn refract(&self, n: Vector3, r: f32) -> Vector3 {
+        let dot = self.dot(n);
+        let d = 1.0 - r * r * (1.0 - dot * dot);
+
+        if d < 0.0 {
+            return Vector3::ZERO;
         }
+
+        return *self * r - n * (r * dot + f32::sqrt(d));
     }
-}
 
-impl Drop for DrawingContext {
-    fn drop(&mut self) {
-        unsafe {
-            EndDrawing();
+    #[must_use]
+    pub fn min(&self, rhs: Vector3) -> Vector3 {
+        return Vector3 { x: f32::min(self.x, rhs.x), y: f32::min(self.y, rhs.y), z: f32::min(self.z, rhs.z) };
+    }
+
+    #[must_use]
+    pub fn max(&self, rhs: Vector3) -> Vector3 {
+        return Vector3 { x: f32::max(self.x, rhs.x), y: f32::max(self.y, rhs.y), z: f32::max(self.z, rhs.z) };
+    }
+
+    // Componentwise.
+    #[must_use]
+    pub fn clamp(&self, min: Vector3, max: Vector3) -> Vector3 {
+        return self.max(min).min(max);
+    }
+
+    // Clamps the length, keeping the direction.
+    #[must_use]
+    pub fn clamp_len(&self, min: f32, max: f32) -> Vector3 {
+        let len = self.len();
+
+        if len == 0.0 {
+            return *self;
         }
+
+        if len < min {
+            return *self * (min / len);
+        }
+
+        if len > max {
+            return *self * (max / len);
+        }
+
+        return *self;
+    }
+
+    // Steps towards target by at most max_distance, without overshooting.
+    #[must_use]
+    pub fn move_towards(&self, target: Vector3, max_distance: f32) -> Vector3 {
+        let delta = target - *self;
+        let dist = delta.len();
+
+        if dist == 0.0 || (max_distance >= 0.0 && dist <= max_distance) {
+            return target;
+        }
+
+        return *self + delta * (max_distance / dist);
+    }
+
+    #[must_use]
+    pub fn invert(&self) -> Vector3 {
+        return Vector3 { x: 1.0 / self.x, y: 1.0 / self.y, z: 1.0 / self.z };
+    }
+
+    // Barycentric coordinates (u, v, w) of p with respect to the triangle (a, b, c).
+    pub fn barycenter(p: Vector3, a: Vector3, b: Vector3, c: Vector3) -> Vector3 {
+        let v0 = b - a;
+        let v1 = c - a;
+        let v2 = p - a;
+        let d00 = v0.dot(v0);
+        let d01 = v0.dot(v1);
+        let d11 = v1.dot(v1);
+        let d20 = v2.dot(v0);
+        let d21 = v2.dot(v1);
+
+        let denom = d00 * d11 - d01 * d01;
+
+        let y = (d11 * d20 - d01 * d21) / denom;
+        let z = (d00 * d21 - d01 * d20) / denom;
+
+        return Vector3 { x: 1.0 - (z + y), y, z };
+    }
+
+    // Takes a point in normalized device coordinates back into world space.
+    pub fn unproject(source: Vector3, projection: Matrix, view: Matrix) -> Vector3 {
+        let view_proj_inverse = (view * projection).invert();
+        let q = Quaternion { x: source.x, y: source.y, z: source.z, w: 1.0 }.transform(view_proj_inverse);
+
+        return Vector3 { x: q.x / q.w, y: q.y / q.w, z: q.z / q.w };
+    }
+
+    // Same tolerance as raymath's Vector3Equals, relative for large values.
+    pub fn approx_eq(&self, rhs: Vector3) -> bool {
+        return approx_eq_f32(self.x, rhs.x) && approx_eq_f32(self.y, rhs.y) && approx_eq_f32(self.z, rhs.z);
+    }
+}
+
+impl Neg for Vector3 {
+    type Output = Vector3;
+
+    fn neg(self) -> Vector3 {
+        return Vector3 {x: -self.x, y: -self.y, z: -self.z};
     }
 }
 
-pub fn init_window_context(width: i32, height: i32, title: &str) -> WindowContext {
-    if width < 0 || height < 0 {
-        panic!("width and height should be non-negative.");
+impl AddAssign for Vector3 {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
     }
+}
 
-    unsafe {
-        InitWindow(width as c_int, height as c_int, CString::new(title).expect("Failed to create CString.").as_ptr());
+impl AddAssign<f32> for Vector3 {
+    fn add_assign(&mut self, rhs: f32) {
+        *self = *self + rhs;
     }
+}
 
-    return WindowContext {};
+impl SubAssign for Vector3 {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
 }
 
-pub fn is_key_down(key: Key) -> bool {
-    let flag;
-    let converted_key = key.to_key_code();
+impl SubAssign<f32> for Vector3 {
+    fn sub_assign(&mut self, rhs: f32) {
+        *self = *self - rhs;
+    }
+}
 
-    unsafe {
-        flag = IsKeyDown(converted_key);
+impl MulAssign for Vector3 {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
     }
+}
 
-    return flag;
+impl MulAssign<f32> for Vector3 {
+    fn mul_assign(&mut self, rhs: f32) {
+        *self = *self * rhs;
+    }
 }
 
-pub fn get_char_pressed() -> Option<char> {
-    let result;
+impl DivAssign for Vector3 {
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
 
-    unsafe {
-        result = GetCharPressed();
+impl DivAssign<f32> for Vector3 {
+    fn div_assign(&mut self, rhs: f32) {
+        *self = *self / rhs;
     }
+}
 
-    match result {
-        0 => {
-            return None;
-        },
-        c => {
-            // Larger than all ascii.
-            if c > 127 {
-                return None;
-            }
+impl Add<Vector3> for Vector3 {
+    type Output = Vector3;
 
-            return Some(c as u8 as char);
-        },
-    };
+    fn add(self, rhs: Vector3) -> Vector3 {
+        return Vector3 {x: self.x + rhs.x, y: self.y + rhs.y, z: self.z + rhs.z};
+    }
 }
 
-pub fn is_key_pressed(key: Key) -> bool {
-    let result;
-    let converted_key = key.to_key_code();
+impl Add<f32> for Vector3 {
+    type Output = Vector3;
 
-    unsafe {
-        result = IsKeyPressed(converted_key);
+    fn add(self, rhs: f32) -> Vector3 {
+        return Vector3 {x: self.x + rhs, y: self.y + rhs, z: self.z + rhs};
     }
+}
 
-    return result;
+impl Sub<Vector3> for Vector3 {
+    type Output = Vector3;
+
+    fn sub(self, rhs: Vector3) -> Vector3 {
+        return Vector3 {x: self.x - rhs.x, y: self.y - rhs.y, z: self.z - rhs.z};
+    }
 }
 
-pub fn is_key_pressed_repeat(key: Key) -> bool {
-    let result;
-    let converted_key = key.to_key_code();
+impl Sub<f32> for Vector3 {
+    type Output = Vector3;
 
-    unsafe {
-        result = IsKeyPressedRepeat(converted_key);
+    fn sub(self, rhs: f32) -> Vector3 {
+        return Vector3 {x: self.x - rhs, y: self.y - rhs, z: self.z - rhs};
     }
+}
 
-    return result;
+impl Mul<f32> for Vector3 {
+    type Output = Vector3;
+
+    fn mul(self, rhs: f32) -> Vector3 {
+        return Vector3 {x: self.x * rhs, y: self.y * rhs, z: self.z * rhs};
+    }
 }
 
-#[derive(Debug)]
-pub enum Key {
-    NULL,                       // Key: NULL, used for no key pressed
-    // Alphanumeric keys
-    APOSTROPHE,                 // Key: '
-    COMMA,                      // Key: ,
-    MINUS,                      // Key: -
-    PERIOD,                     // Key: .
-    SLASH,                      // Key: /
-    ZERO,                       // Key: 0
-    ONE,                        // Key: 1
-    TWO,                        // Key: 2
-    THREE,                      // Key: 3
-    FOUR,                       // Key: 4
-    FIVE,                       // Key: 5
-    SIX,                        // Key: 6
-    SEVEN,                      // Key: 7
-    EIGHT,                      // Key: 8
-    NINE,                       // Key: 9
-    SEMICOLON,                  // Key: ;
-    EQUAL,                      // Key: =
-    A,                          // Key: A | a
-    B,                          // Key: B | b
-    C,                          // Key: C | c
-    D,                          // Key: D | d
-    E,                          // Key: E | e
-    F,                          // Key: F | f
-    G,                          // Key: G | g
-    H,                          // Key: H | h
-    I,                          // Key: I | i
-    J,                          // Key: J | j
-    K,                          // Key: K | k
-    L,                          // Key: L | l
-    M,                          // Key: M | m
-    N,                          // Key: N | n
-    O,                          // Key: O | o
-    P,                          // Key: P | p
-    Q,                          // Key: Q | q
-    R,                          // Key: R | r
-    S,                          // Key: S | s
-    T,                          // Key: T | t
-    U,                          // Key: U | u
-    V,                          // Key: V | v
-    W,                          // Key: W | w
-    X,                          // Key: X | x
-    Y,                          // Key: Y | y
-    Z,                          // Key: Z | z
-    LEFT_BRACKET,               // Key: [
-    BACKSLASH,                  // Key: '\'
-    RIGHT_BRACKET,              // Key: ]
-    GRAVE,                      // Key: `
-    // Function keys
-    SPACE,                      // Key: Space
-    ESCAPE,                     // Key: Esc
-    ENTER,                      // Key: Enter
-    TAB,                        // Key: Tab
-    BACKSPACE,                  // Key: Backspace
-    INSERT,                     // Key: Ins
-    DELETE,                     // Key: Del
-    RIGHT,                      // Key: Cursor right
-    LEFT,                       // Key: Cursor left
-    DOWN,                       // Key: Cursor down
-    UP,                         // Key: Cursor up
-    PAGE_UP,                    // Key: Page up
-    PAGE_DOWN,                  // Key: Page down
-    HOME,                       // Key: Home
-    END,                        // Key: End
-    CAPS_LOCK,                  // Key: Caps lock
-    SCROLL_LOCK,                // Key: Scroll down
-    NUM_LOCK,                   // Key: Num lock
-    PRINT_SCREEN,               // Key: Print screen
-    PAUSE,                      // Key: Pause
-    F1,                         // Key: F1
-    F2,                         // Key: F2
-    F3,                         // Key: F3
-    F4,                         // Key: F4
-    F5,                         // Key: F5
-    F6,                         // Key: F6
-    F7,                         // Key: F7
-    F8,                         // Key: F8
-    F9,                         // Key: F9
-    F10,                        // Key: F10
-    F11,                        // Key: F11
-    F12,                        // Key: F12
-    LEFT_SHIFT,                 // Key: Shift left
-    LEFT_CONTROL,               // Key: Control left
-    LEFT_ALT,                   // Key: Alt left
-    LEFT_SUPER,                 // Key: Super left
-    RIGHT_SHIFT,                // Key: Shift right
-    RIGHT_CONTROL,              // Key: Control right
-    RIGHT_ALT,                  // Key: Alt right
-    RIGHT_SUPER,                // Key: Super right
-    KB_MENU,                    // Key: KB menu
-    // Keypad keys
-    KP_0,                       // Key: Keypad 0
-    KP_1,                       // Key: Keypad 1
-    KP_2,                       // Key: Keypad 2
-    KP_3,                       // Key: Keypad 3
-    KP_4,                       // Key: Keypad 4
-    KP_5,                       // Key: Keypad 5
-    KP_6,                       // Key: Keypad 6
-    KP_7,                       // Key: Keypad 7
-    KP_8,                       // Key: Keypad 8
-    KP_9,                       // Key: Keypad 9
-    KP_DECIMAL,                 // Key: Keypad .
-    KP_DIVIDE,                  // Key: Keypad /
-    KP_MULTIPLY,                // Key: Keypad *
-    KP_SUBTRACT,                // Key: Keypad -
-    KP_ADD,                     // Key: Keypad +
-    KP_ENTER,                   // Key: Keypad Enter
-    KP_EQUAL,                   // Key: Keypad =
-    // Android key buttons
-    BACK,                       // Key: Android back button
-    MENU,                       // Key: Android menu button
-    VOLUME_UP,                  // Key: Android volume up button
-    VOLUME_DOWN,                // Key: Android volume down button
+impl Mul<Vector3> for f32 {
+    type Output = Vector3;
+
+    fn mul(self, rhs: Vector3) -> Vector3 {
+        return Vector3 {x: self * rhs.x, y: self * rhs.y, z: self * rhs.z};
+    }
 }
 
-impl Key {
-    fn to_key_code(&self) -> c_int {
-        match self {
-            Key::NULL            => 0,        // Key: NULL, used for no key pressed
-            // Alphanumeric keys
-            Key::APOSTROPHE      => 39,       // Key: '
-            Key::COMMA           => 44,       // Key: ,
-            Key::MINUS           => 45,       // Key: -
-            Key::PERIOD          => 46,       // Key: .
-            Key::SLASH           => 47,       // Key: /
-            Key::ZERO            => 48,       // Key: 0
+impl Mul<Vector3> for Vector3 {
+    type Output = Vector3;
+
+    fn mul(self, rhs: Vector3) -> Vector3 {
+        return Vector3 {x: self.x * rhs.x, y: self.y * rhs.y, z: self.z * rhs.z};
+    }
+}
+
+impl Div<f32> for Vector3 {
+    type Output = Vector3;
+
+    fn div(self, rhs: f32) -> Vector3 {
+        return Vector3 {x: self.x / rhs, y: self.y / rhs, z: self.z / rhs};
+    }
+}
+
+impl Div<Vector3> for Vector3 {
+    type Output = Vector3;
+
+    fn div(self, rhs: Vector3) -> Vector3 {
+        return Vector3 {x: self.x / rhs.x, y: self.y / rhs.y, z: self.z / rhs.z};
+    }
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Vector4 {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+    pub w: f32,
+}
+
+impl Vector4 {
+    pub const ZERO: Vector4 = Vector4 { x: 0.0, y: 0.0, z: 0.0, w: 0.0 };
+    pub const ONE: Vector4 = Vector4 { x: 1.0, y: 1.0, z: 1.0, w: 1.0 };
+
+    pub fn new(x: f32, y: f32, z: f32, w: f32) -> Vector4 {
+        return Vector4 { x, y, z, w };
+    }
+
+    pub fn len(&self) -> f32 {
+        return f32::sqrt(self.len_sqr());
+    }
+
+    pub fn len_sqr(&self) -> f32 {
+        return self.dot(*self);
+    }
+
+    pub fn dot(&self, rhs: Vector4) -> f32 {
+        return self.x * rhs.x + self.y * rhs.y + self.z * rhs.z + self.w * rhs.w;
+    }
+
+    pub fn distance(&self, rhs: Vector4) -> f32 {
+        return (rhs - *self).len();
+    }
+
+    pub fn normalize(&mut self) {
+        *self = self.normalized();
+    }
+
+    #[must_use]
+    pub fn normalized(&self) -> Vector4 {
+        let len = self.len();
+
+        if len == 0.0 {
+            return *self;
+        }
+
+        return *self * (1.0 / len);
+    }
+
+    #[must_use]
+    pub fn lerp(&self, rhs: Vector4, amount: f32) -> Vector4 {
+        return *self + (rhs - *self) * amount;
+    }
+
+    #[must_use]
+    pub fn min(&self, rhs: Vector4) -> Vector4 {
+        return Vector4 { x: f32::min(self.x, rhs.x), y: f32::min(self.y, rhs.y), z: f32::min(self.z, rhs.z), w: f32::min(self.w, rhs.w) };
+    }
+
+    #[must_use]
+    pub fn max(&self, rhs: Vector4) -> Vector4 {
+        return Vector4 { x: f32::max(self.x, rhs.x), y: f32::max(self.y, rhs.y), z: f32::max(self.z, rhs.z), w: f32::max(self.w, rhs.w) };
+    }
+
+    pub fn approx_eq(&self, rhs: Vector4) -> bool {
+        return approx_eq_f32(self.x, rhs.x) && approx_eq_f32(self.y, rhs.y) && approx_eq_f32(self.z, rhs.z) && approx_eq_f32(self.w, rhs.w);
+    }
+}
+
+impl Neg for Vector4 {
+    type Output = Vector4;
+
+    fn neg(self) -> Vector4 {
+        return Vector4 {x: -self.x, y: -self.y, z: -self.z, w: -self.w};
+    }
+}
+
+impl AddAssign for Vector4 {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl SubAssign for Vector4 {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl MulAssign<f32> for Vector4 {
+    fn mul_assign(&mut self, rhs: f32) {
+        *self = *self * rhs;
+    }
+}
+
+impl MulAssign for Vector4 {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl DivAssign<f32> for Vector4 {
+    fn div_assign(&mut self, rhs: f32) {
+        *self = *self / rhs;
+    }
+}
+
+impl Add<Vector4> for Vector4 {
+    type Output = Vector4;
+
+    fn add(self, rhs: Vector4) -> Vector4 {
+        return Vector4 {x: self.x + rhs.x, y: self.y + rhs.y, z: self.z + rhs.z, w: self.w + rhs.w};
+    }
+}
+
+impl Sub<Vector4> for Vector4 {
+    type Output = Vector4;
+
+    fn sub(self, rhs: Vector4) -> Vector4 {
+        return Vector4 {x: self.x - rhs.x, y: self.y - rhs.y, z: self.z - rhs.z, w: self.w - rhs.w};
+    }
+}
+
+impl Mul<f32> for Vector4 {
+    type Output = Vector4;
+
+    fn mul(self, rhs: f32) -> Vector4 {
+        return Vector4 {x: self.x * rhs, y: self.y * rhs, z: self.z * rhs, w: self.w * rhs};
+    }
+}
+
+impl Mul<Vector4> for Vector4 {
+    type Output = Vector4;
+
+    fn mul(self, rhs: Vector4) -> Vector4 {
+        return Vector4 {x: self.x * rhs.x, y: self.y * rhs.y, z: self.z * rhs.z, w: self.w * rhs.w};
+    }
+}
+
+impl Div<f32> for Vector4 {
+    type Output = Vector4;
+
+    fn div(self, rhs: f32) -> Vector4 {
+        return Vector4 {x: self.x / rhs, y: self.y / rhs, z: self.z / rhs, w: self.w / rhs};
+    }
+}
+
+// Same layout as Vector4 (raylib typedefs one to the other), but `*` is the Hamilton product.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Quaternion {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+    pub w: f32,
+}
+
+impl Default for Quaternion {
+    fn default() -> Quaternion {
+        return Quaternion::IDENTITY;
+    }
+}
+
+impl From<Vector4> for Quaternion {
+    fn from(v: Vector4) -> Quaternion {
+        return Quaternion { x: v.x, y: v.y, z: v.z, w: v.w };
+    }
+}
+
+impl From<Quaternion> for Vector4 {
+    fn from(q: Quaternion) -> Vector4 {
+        return Vector4 { x: q.x, y: q.y, z: q.z, w: q.w };
+    }
+}
+
+impl Quaternion {
+    pub const IDENTITY: Quaternion = Quaternion { x: 0.0, y: 0.0, z: 0.0, w: 1.0 };
+
+    pub fn new(x: f32, y: f32, z: f32, w: f32) -> Quaternion {
+        return Quaternion { x, y, z, w };
+    }
+
+    pub fn len(&self) -> f32 {
+        return Vector4::from(*self).len();
+    }
+
+    pub fn dot(&self, rhs: Quaternion) -> f32 {
+        return Vector4::from(*self).dot(Vector4::from(rhs));
+    }
+
+    pub fn normalize(&mut self) {
+        *self = self.normalized();
+    }
+
+    #[must_use]
+    pub fn normalized(&self) -> Quaternion {
+        return Quaternion::from(Vector4::from(*self).normalized());
+    }
+
+    #[must_use]
+    pub fn invert(&self) -> Quaternion {
+        let len_sqr = Vector4::from(*self).len_sqr();
+
+        if len_sqr == 0.0 {
+            return *self;
+        }
+
+        let inv = 1.0 / len_sqr;
+
+        return Quaternion { x: -self.x * inv, y: -self.y * inv, z: -self.z * inv, w: self.w * inv };
+    }
+
+    #[must_use]
+    pub fn lerp(&self, rhs: Quaternion, amount: f32) -> Quaternion {
+        return Quaternion::from(Vector4::from(*self).lerp(Vector4::from(rhs), amount));
+    }
+
+    #[must_use]
+    pub fn nlerp(&self, rhs: Quaternion, amount: f32) -> Quaternion {
+        return self.lerp(rhs, amount).normalized();
+    }
+
+    #[must_use]
+    pub fn slerp(&self, rhs: Quaternion, amount: f32) -> Quaternion {
+        let q1 = Vector4::from(*self);
+        let mut q2 = Vector4::from(rhs);
+        let mut cos_half_theta = q1.dot(q2);
+
+        // Take the short way around.
+        if cos_half_theta < 0.0 {
+            q2 = -q2;
+            cos_half_theta = -cos_half_theta;
+        }
+
+        if f32::abs(cos_half_theta) >= 1.0 {
+            return *self;
+        }
+
+        if cos_half_theta > 0.95 {
+            return self.nlerp(Quaternion::from(q2), amount);
+        }
+
+        let half_theta = f32::acos(cos_half_theta);
+        let sin_half_theta = f32::sqrt(1.0 - cos_half_theta * cos_half_theta);
+
+        if f32::abs(sin_half_theta) < EPSILON {
+            return Quaternion::from(q1 * 0.5 + q2 * 0.5);
+        }
+
+        let ratio_a = f32::sin((1.0 - amount) * half_theta) / sin_half_theta;
+        let ratio_b = f32::sin(amount * half_theta) / sin_half_theta;
+
+        return Quaternion::from(q1 * ratio_a + q2 * ratio_b);
+    }
+
+    // The rotation taking from onto to. Both should be normalized.
+    pub fn from_vector3_to_vector3(from: Vector3, to: Vector3) -> Quaternion {
+        let cos2_theta = from.dot(to);
+        let cross = from.cross(to);
+
+        return Quaternion { x: cross.x, y: cross.y, z: cross.z, w: 1.0 + cos2_theta }.normalized();
+    }
+
+    // Only the rotation part of mat is used.
+    pub fn from_matrix(mat: Matrix) -> Quaternion {
+        let four_w_squared_minus_1 = mat.m0 + mat.m5 + mat.m10;
+        let four_x_squared_minus_1 = mat.m0 - mat.m5 - mat.m10;
+        let four_y_squared_minus_1 = mat.m5 - mat.m0 - mat.m10;
+        let four_z_squared_minus_1 = mat.m10 - mat.m0 - mat.m5;
+
+        let mut biggest_index = 0;
+        let mut four_biggest_squared_minus_1 = four_w_squared_minus_1;
+
+        if four_x_squared_minus_1 > four_biggest_squared_minus_1 {
+            four_biggest_squared_minus_1 = four_x_squared_minus_1;
+            biggest_index = 1;
+        }
+
+        if four_y_squared_minus_1 > four_biggest_squared_minus_1 {
+            four_biggest_squared_minus_1 = four_y_squared_minus_1;
+            biggest_index = 2;
+        }
+
+        if four_z_squared_minus_1 > four_biggest_squared_minus_1 {
+            four_biggest_squared_minus_1 = four_z_squared_minus_1;
+            biggest_index = 3;
+        }
+
+        let biggest_val = f32::sqrt(four_biggest_squared_minus_1 + 1.0) * 0.5;
+        let mult = 0.25 / biggest_val;
+
+        match biggest_index {
+            0 => Quaternion {
+                x: (mat.m6 - mat.m9) * mult,
+                y: (mat.m8 - mat.m2) * mult,
+                z: (mat.m1 - mat.m4) * mult,
+                w: biggest_val,
+            },
+            1 => Quaternion {
+                x: biggest_val,
+                y: (mat.m1 + mat.m4) * mult,
+                z: (mat.m8 + mat.m2) * mult,
+                w: (mat.m6 - mat.m9) * mult,
+            },
+            2 => Quaternion {
+                x: (mat.m1 + mat.m4) * mult,
+                y: biggest_val,
+                z: (mat.m6 + mat.m9) * mult,
+                w: (mat.m8 - mat.m2) * mult,
+            },
+            _ => Quaternion {
+                x: (mat.m8 + mat.m2) * mult,
+                y: (mat.m6 + mat.m9) * mult,
+                z: biggest_val,
+                w: (mat.m1 - mat.m4) * mult,
+            },
+        }
+    }
+
+    pub fn to_matrix(&self) -> Matrix {
+        let a2 = self.x * self.x;
+        let b2 = self.y * self.y;
+        let c2 = self.z * self.z;
+        let ac = self.x * self.z;
+        let ab = self.x * self.y;
+        let bc = self.y * self.z;
+        let ad = self.w * self.x;
+        let bd = self.w * self.y;
+        let cd = self.w * self.z;
+
+        let mut result = Matrix::identity();
+
+        result.m0 = 1.0 - 2.0 * (b2 + c2);
+        result.m1 = 2.0 * (ab + cd);
+        result.m2 = 2.0 * (ac - bd);
+
+        result.m4 = 2.0 * (ab - cd);
+        result.m5 = 1.0 - 2.0 * (a2 + c2);
+        result.m6 = 2.0 * (bc + ad);
+
+        result.m8 = 2.0 * (ac + bd);
+        result.m9 = 2.0 * (bc - ad);
+        result.m10 = 1.0 - 2.0 * (a2 + b2);
+
+        return result;
+    }
+
+    // Angle in radians. A zero axis gives the identity.
+    pub fn from_axis_angle(axis: Vector3, angle: f32) -> Quaternion {
+        if axis.len() == 0.0 {
+            return Quaternion::IDENTITY;
+        }
+
+        let axis = axis.normalized();
+        let half_angle = angle * 0.5;
+        let sinres = f32::sin(half_angle);
+        let cosres = f32::cos(half_angle);
+
+        return Quaternion { x: axis.x * sinres, y: axis.y * sinres, z: axis.z * sinres, w: cosres }.normalized();
+    }
+
+    pub fn to_axis_angle(&self) -> (Vector3, f32) {
+        let mut q = *self;
+
+        if f32::abs(q.w) > 1.0 {
+            q = q.normalized();
+        }
+
+        let angle = 2.0 * f32::acos(q.w);
+        let den = f32::sqrt(1.0 - q.w * q.w);
+
+        if den > EPSILON {
+            return (Vector3 { x: q.x / den, y: q.y / den, z: q.z / den }, angle);
+        }
+
+        // Any axis works when the angle is zero.
+        return (Vector3 { x: 1.0, y: 0.0, z: 0.0 }, angle);
+    }
+
+    // Angles in radians.
+    pub fn from_euler(pitch: f32, yaw: f32, roll: f32) -> Quaternion {
+        let x0 = f32::cos(pitch * 0.5);
+        let x1 = f32::sin(pitch * 0.5);
+        let y0 = f32::cos(yaw * 0.5);
+        let y1 = f32::sin(yaw * 0.5);
+        let z0 = f32::cos(roll * 0.5);
+        let z1 = f32::sin(roll * 0.5);
+
+        return Quaternion {
+            x: x1 * y0 * z0 - x0 * y1 * z1,
+            y: x0 * y1 * z0 + x1 * y0 * z1,
+            z: x0 * y0 * z1 - x1 * y1 * z0,
+            w: x0 * y0 * z0 + x1 * y1 * z1,
+        };
+    }
+
+    // Returns (roll, pitch, yaw) as x, y and z, in radians.
+    pub fn to_euler(&self) -> Vector3 {
+        let q = self;
+
+        let x0 = 2.0 * (q.w * q.x + q.y * q.z);
+        let x1 = 1.0 - 2.0 * (q.x * q.x + q.y * q.y);
+
+        let y0 = f32::clamp(2.0 * (q.w * q.y - q.z * q.x), -1.0, 1.0);
+
+        let z0 = 2.0 * (q.w * q.z + q.x * q.y);
+        let z1 = 1.0 - 2.0 * (q.y * q.y + q.z * q.z);
+
+        return Vector3 {
+            x: f32::atan2(x0, x1),
+            y: f32::asin(y0),
+            z: f32::atan2(z0, z1),
+        };
+    }
+
+    #[must_use]
+    pub fn transform(&self, mat: Matrix) -> Quaternion {
+        let q = self;
+
+        return Quaternion {
+            x: mat.m0 * q.x + mat.m4 * q.y + mat.m8 * q.z + mat.m12 * q.w,
+            y: mat.m1 * q.x + mat.m5 * q.y + mat.m9 * q.z + mat.m13 * q.w,
+            z: mat.m2 * q.x + mat.m6 * q.y + mat.m10 * q.z + mat.m14 * q.w,
+            w: mat.m3 * q.x + mat.m7 * q.y + mat.m11 * q.z + mat.m15 * q.w,
+        };
+    }
+
+    pub fn approx_eq(&self, rhs: Quaternion) -> bool {
+        let p = Vector4::from(*self);
+        let q = Vector4::from(rhs);
+
+        // q and -q are the same rotation.
+        return p.approx_eq(q) || p.approx_eq(-q);
+    }
+}
+
+impl Mul<Quaternion> for Quaternion {
+    type Output = Quaternion;
+
+    fn mul(self, rhs: Quaternion) -> Quaternion {
+        let (qax, qay, qaz, qaw) = (self.x, self.y, self.z, self.w);
+        let (qbx, qby, qbz, qbw) = (rhs.x, rhs.y, rhs.z, rhs.w);
+
+        return Quaternion {
+            x: qax * qbw + qaw * qbx + qay * qbz - qaz * qby,
+            y: qay * qbw + qaw * qby + qaz * qbx - qax * qbz,
+            z: qaz * qbw + qaw * qbz + qax * qby - qay * qbx,
+            w: qaw * qbw - qax * qbx - qay * qby - qaz * qbz,
+        };
+    }
+}
+
+impl MulAssign<Quaternion> for Quaternion {
+    fn mul_assign(&mut self, rhs: Quaternion) {
+        *self = *self * rhs;
+    }
+}
+
+// Column-major, like OpenGL: m0-m3 is the first column, m12-m14 is the translation.
+// Fields are declared in raylib's (row by row) order to match its layout.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Matrix {
+    pub m0: f32, pub m4: f32, pub m8: f32, pub m12: f32,
+    pub m1: f32, pub m5: f32, pub m9: f32, pub m13: f32,
+    pub m2: f32, pub m6: f32, pub m10: f32, pub m14: f32,
+    pub m3: f32, pub m7: f32, pub m11: f32, pub m15: f32,
+}
+
+impl Matrix {
+    pub fn zero() -> Matrix {
+        return Matrix::from_float_array([0.0; 16]);
+    }
+
+    pub fn identity() -> Matrix {
+        let mut result = Matrix::zero();
+
+        result.m0 = 1.0;
+        result.m5 = 1.0;
+        result.m10 = 1.0;
+        result.m15 = 1.0;
+
+        return result;
+    }
+
+    // Indexed by the field number: [m0, m1, m2, ..., m15]. The layout OpenGL expects.
+    pub fn to_float_array(&self) -> [f32; 16] {
+        return [
+            self.m0, self.m1, self.m2, self.m3,
+            self.m4, self.m5, self.m6, self.m7,
+            self.m8, self.m9, self.m10, self.m11,
+            self.m12, self.m13, self.m14, self.m15,
+        ];
+    }
+
+    pub fn from_float_array(m: [f32; 16]) -> Matrix {
+        return Matrix {
+            m0: m[0], m4: m[4], m8: m[8], m12: m[12],
+            m1: m[1], m5: m[5], m9: m[9], m13: m[13],
+            m2: m[2], m6: m[6], m10: m[10], m14: m[14],
+            m3: m[3], m7: m[7], m11: m[11], m15: m[15],
+        };
+    }
+
+    pub fn determinant(&self) -> f32 {
+        let (a00, a01, a02, a03) = (self.m0, self.m1, self.m2, self.m3);
+        let (a10, a11, a12, a13) = (self.m4, self.m5, self.m6, self.m7);
+        let (a20, a21, a22, a23) = (self.m8, self.m9, self.m10, self.m11);
+        let (a30, a31, a32, a33) = (self.m12, self.m13, self.m14, self.m15);
+
+        return a30*a21*a12*a03 - a20*a31*a12*a03 - a30*a11*a22*a03 + a10*a31*a22*a03 +
+               a20*a11*a32*a03 - a10*a21*a32*a03 - a30*a21*a02*a13 + a20*a31*a02*a13 +
+               a30*a01*a22*a13 - a00*a31*a22*a13 - a20*a01*a32*a13 + a00*a21*a32*a13 +
+               a30*a11*a02*a23 - a10*a31*a02*a23 - a30*a01*a12*a23 + a00*a31*a12*a23 +
+               a10*a01*a32*a23 - a00*a11*a32*a23 - a20*a11*a02*a33 + a10*a21*a02*a33 +
+               a20*a01*a12*a33 - a00*a21*a12*a33 - a10*a01*a22*a33 + a00*a11*a22*a33;
+    }
+
+    pub fn trace(&self) -> f32 {
+        return self.m0 + self.m5 + self.m10 + self.m15;
+    }
+
+    #[must_use]
+    pub fn transpose(&self) -> Matrix {
+        return Matrix {
+            m0: self.m0, m4: self.m1, m8: self.m2, m12: self.m3,
+            m1: self.m4, m5: self.m5, m9: self.m6, m13: self.m7,
+            m2: self.m8, m6: self.m9, m10: self.m10, m14: self.m11,
+            m3: self.m12, m7: self.m13, m11: self.m14, m15: self.m15,
+        };
+    }
+
+    // A singular matrix comes back full of infinities, as in raymath.
+    #[must_use]
+    pub fn invert(&self) -> Matrix {
+        let (a00, a01, a02, a03) = (self.m0, self.m1, self.m2, self.m3);
+        let (a10, a11, a12, a13) = (self.m4, self.m5, self.m6, self.m7);
+        let (a20, a21, a22, a23) = (self.m8, self.m9, self.m10, self.m11);
+        let (a30, a31, a32, a33) = (self.m12, self.m13, self.m14, self.m15);
+
+        let b00 = a00*a11 - a01*a10;
+        let b01 = a00*a12 - a02*a10;
+        let b02 = a00*a13 - a03*a10;
+        let b03 = a01*a12 - a02*a11;
+        let b04 = a01*a13 - a03*a11;
+        let b05 = a02*a13 - a03*a12;
+        let b06 = a20*a31 - a21*a30;
+        let b07 = a20*a32 - a22*a30;
+        let b08 = a20*a33 - a23*a30;
+        let b09 = a21*a32 - a22*a31;
+        let b10 = a21*a33 - a23*a31;
+        let b11 = a22*a33 - a23*a32;
+
+        let inv_det = 1.0 / (b00*b11 - b01*b10 + b02*b09 + b03*b08 - b04*b07 + b05*b06);
+
+        return Matrix::from_float_array([
+            (a11*b11 - a12*b10 + a13*b09) * inv_det,
+            (-a01*b11 + a02*b10 - a03*b09) * inv_det,
+            (a31*b05 - a32*b04 + a33*b03) * inv_det,
+            (-a21*b05 + a22*b04 - a23*b03) * inv_det,
+            (-a10*b11 + a12*b08 - a13*b07) * inv_det,
+            (a00*b11 - a02*b08 + a03*b07) * inv_det,
+            (-a30*b05 + a32*b02 - a33*b01) * inv_det,
+            (a20*b05 - a22*b02 + a23*b01) * inv_det,
+            (a10*b10 - a11*b08 + a13*b06) * inv_det,
+            (-a00*b10 + a01*b08 - a03*b06) * inv_det,
+            (a30*b04 - a31*b02 + a33*b00) * inv_det,
+            (-a20*b04 + a21*b02 - a23*b00) * inv_det,
+            (-a10*b09 + a11*b07 - a12*b06) * inv_det,
+            (a00*b09 - a01*b07 + a02*b06) * inv_det,
+            (-a30*b03 + a31*b01 - a32*b00) * inv_det,
+            (a20*b03 - a21*b01 + a22*b00) * inv_det,
+        ]);
+    }
+
+    pub fn translate(x: f32, y: f32, z: f32) -> Matrix {
+        let mut result = Matrix::identity();
+
+        result.m12 = x;
+        result.m13 = y;
+        result.m14 = z;
+
+        return result;
+    }
+
+    // Angle in radians.
+    pub fn rotate(axis: Vector3, angle: f32) -> Matrix {
+        let Vector3 { x, y, z } = axis.normalized();
+
+        let sinres = f32::sin(angle);
+        let cosres = f32::cos(angle);
+        let t = 1.0 - cosres;
+
+        let mut result = Matrix::identity();
+
+        result.m0 = x*x*t + cosres;
+        result.m1 = y*x*t + z*sinres;
+        result.m2 = z*x*t - y*sinres;
+
+        result.m4 = x*y*t - z*sinres;
+        result.m5 = y*y*t + cosres;
+        result.m6 = z*y*t + x*sinres;
+
+        result.m8 = x*z*t + y*sinres;
+        result.m9 = y*z*t - x*sinres;
+        result.m10 = z*z*t + cosres;
+
+        return result;
+    }
+
+    pub fn rotate_x(angle: f32) -> Matrix {
+        let cosres = f32::cos(angle);
+        let sinres = f32::sin(angle);
+        let mut result = Matrix::identity();
+
+        result.m5 = cosres;
+        result.m6 = sinres;
+        result.m9 = -sinres;
+        result.m10 = cosres;
+
+        return result;
+    }
+
+    pub fn rotate_y(angle: f32) -> Matrix {
+        let cosres = f32::cos(angle);
+        let sinres = f32::sin(angle);
+        let mut result = Matrix::identity();
+
+        result.m0 = cosres;
+        result.m2 = -sinres;
+        result.m8 = sinres;
+        result.m10 = cosres;
+
+        return result;
+    }
+
+    pub fn rotate_z(angle: f32) -> Matrix {
+        let cosres = f32::cos(angle);
+        let sinres = f32::sin(angle);
+        let mut result = Matrix::identity();
+
+        result.m0 = cosres;
+        result.m1 = sinres;
+        result.m4 = -sinres;
+        result.m5 = cosres;
+
+        return result;
+    }
+
+    // Angles in radians, applied x, then y, then z.
+    pub fn rotate_xyz(angle: Vector3) -> Matrix {
+        let cosz = f32::cos(-angle.z);
+        let sinz = f32::sin(-angle.z);
+        let cosy = f32::cos(-angle.y);
+        let siny = f32::sin(-angle.y);
+        let cosx = f32::cos(-angle.x);
+        let sinx = f32::sin(-angle.x);
+
+        let mut result = Matrix::identity();
+
+        result.m0 = cosz*cosy;
+        result.m1 = (cosz*siny*sinx) - (sinz*cosx);
+        result.m2 = (cosz*siny*cosx) + (sinz*sinx);
+
+        result.m4 = sinz*cosy;
+        result.m5 = (sinz*siny*sinx) + (cosz*cosx);
+        result.m6 = (sinz*siny*cosx) - (cosz*sinx);
+
+        result.m8 = -siny;
+        result.m9 = cosy*sinx;
+        result.m10 = cosy*cosx;
+
+        return result;
+    }
+
+    // Angles in radians, applied z, then y, then x.
+    pub fn rotate_zyx(angle: Vector3) -> Matrix {
+        let cz = f32::cos(angle.z);
+        let sz = f32::sin(angle.z);
+        let cy = f32::cos(angle.y);
+        let sy = f32::sin(angle.y);
+        let cx = f32::cos(angle.x);
+        let sx = f32::sin(angle.x);
+
+        let mut result = Matrix::identity();
+
+        result.m0 = cz*cy;
+        result.m4 = cz*sy*sx - cx*sz;
+        result.m8 = sz*sx + cz*cx*sy;
+
+        result.m1 = cy*sz;
+        result.m5 = cz*cx + sz*sy*sx;
+        result.m9 = cx*sz*sy - cz*sx;
+
+        result.m2 = -sy;
+        result.m6 = cy*sx;
+        result.m10 = cy*cx;
+
+        return result;
+    }
+
+    pub fn scale(x: f32, y: f32, z: f32) -> Matrix {
+        let mut result = Matrix::identity();
+
+        result.m0 = x;
+        result.m5 = y;
+        result.m10 = z;
+
+        return result;
+    }
+
+    pub fn frustum(left: f64, right: f64, bottom: f64, top: f64, near: f64, far: f64) -> Matrix {
+        let rl = right - left;
+        let tb = top - bottom;
+        let fnr = far - near;
+
+        let mut result = Matrix::zero();
+
+        result.m0 = ((near*2.0)/rl) as f32;
+        result.m5 = ((near*2.0)/tb) as f32;
+        result.m8 = ((right + left)/rl) as f32;
+        result.m9 = ((top + bottom)/tb) as f32;
+        result.m10 = (-(far + near)/fnr) as f32;
+        result.m11 = -1.0;
+        result.m14 = (-(far*near*2.0)/fnr) as f32;
+
+        return result;
+    }
+
+    // fovy in radians.
+    pub fn perspective(fovy: f64, aspect: f64, near: f64, far: f64) -> Matrix {
+        let top = near * f64::tan(fovy * 0.5);
+        let right = top * aspect;
+
+        return Matrix::frustum(-right, right, -top, top, near, far);
+    }
+
+    pub fn ortho(left: f64, right: f64, bottom: f64, top: f64, near: f64, far: f64) -> Matrix {
+        let rl = right - left;
+        let tb = top - bottom;
+        let fnr = far - near;
+
+        let mut result = Matrix::zero();
+
+        result.m0 = (2.0/rl) as f32;
+        result.m5 = (2.0/tb) as f32;
+        result.m10 = (-2.0/fnr) as f32;
+        result.m12 = (-(left + right)/rl) as f32;
+        result.m13 = (-(top + bottom)/tb) as f32;
+        result.m14 = (-(far + near)/fnr) as f32;
+        result.m15 = 1.0;
+
+        return result;
+    }
+
+    // View matrix for a camera at eye looking at target.
+    pub fn look_at(eye: Vector3, target: Vector3, up: Vector3) -> Matrix {
+        let vz = (eye - target).normalized();
+        let vx = up.cross(vz).normalized();
+        let vy = vz.cross(vx);
+
+        return Matrix {
+            m0: vx.x, m4: vx.y, m8: vx.z, m12: -vx.dot(eye),
+            m1: vy.x, m5: vy.y, m9: vy.z, m13: -vy.dot(eye),
+            m2: vz.x, m6: vz.y, m10: vz.z, m14: -vz.dot(eye),
+            m3: 0.0, m7: 0.0, m11: 0.0, m15: 1.0,
+        };
+    }
+}
+
+impl Add<Matrix> for Matrix {
+    type Output = Matrix;
+
+    fn add(self, rhs: Matrix) -> Matrix {
+        let l = self.to_float_array();
+        let r = rhs.to_float_array();
+        let mut result = [0.0; 16];
+
+        for i in 0..16 {
+            result[i] = l[i] + r[i];
+        }
+
+        return Matrix::from_float_array(result);
+    }
+}
+
+impl Sub<Matrix> for Matrix {
+    type Output = Matrix;
+
+    fn sub(self, rhs: Matrix) -> Matrix {
+        let l = self.to_float_array();
+        let r = rhs.to_float_array();
+        let mut result = [0.0; 16];
+
+        for i in 0..16 {
+            result[i] = l[i] - r[i];
+        }
+
+        return Matrix::from_float_array(result);
+    }
+}
+
+// Same as raymath's MatrixMultiply(self, rhs): self is applied first.
+impl Mul<Matrix> for Matrix {
+    type Output = Matrix;
+
+    fn mul(self, rhs: Matrix) -> Matrix {
+        let l = self.to_float_array();
+        let r = rhs.to_float_array();
+        let mut result = [0.0; 16];
+
+        for row in 0..4 {
+            for col in 0..4 {
+                for k in 0..4 {
+                    result[row * 4 + col] += l[row * 4 + k] * r[k * 4 + col];
+                }
+            }
+        }
+
+        return Matrix::from_float_array(result);
+    }
+}
+
+impl MulAssign<Matrix> for Matrix {
+    fn mul_assign(&mut self, rhs: Matrix) {
+        *self = *self * rhs;
+    }
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Ray {
+    pub position: Vector3,
+    pub direction: Vector3,
+}
+
+// Axis-aligned.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct BoundingBox {
+    pub min: Vector3,
+    pub max: Vector3,
+}
+
+// Where a ray hit something. normal is the surface normal at point.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct RayCollision {
+    pub distance: f32,
+    pub point: Vector3,
+    pub normal: Vector3,
+}
+
+impl RayCollision {
+    fn from_cray_collision(collision: CRayCollision) -> Option<RayCollision> {
+        if !collision.hit {
+            return None;
+        }
+
+        return Some(RayCollision {
+            distance: collision.distance,
+            point: collision.point,
+            normal: collision.normal,
+        });
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Rectangle {
+    pub x: f32,
+    pub y: f32,
+    pub width: f32,
+    pub height: f32,
+}
+
+impl Rectangle {
+    fn to_crectangle(&self) -> CRectangle {
+        return CRectangle {
+            x: self.x,
+            y: self.y,
+            w: self.width,
+            h: self.height,
+        };
+    }
+
+    fn from_crectangle(rec: CRectangle) -> Rectangle {
+        return Rectangle {
+            x: rec.x,
+            y: rec.y,
+            width: rec.w,
+            height: rec.h,
+        };
+    }
+
+    pub fn new(x: f32, y: f32, width: f32, height: f32) -> Rectangle {
+        return Rectangle { x, y, width, height };
+    }
+
+    pub fn from_position_and_size(position: Vector2, size: Vector2) -> Rectangle {
+        return Rectangle { x: position.x, y: position.y, width: size.x, height: size.y };
+    }
+
+    pub fn position(&self) -> Vector2 {
+        return Vector2 { x: self.x, y: self.y };
+    }
+
+    pub fn size(&self) -> Vector2 {
+        return Vector2 { x: self.width, y: self.height };
+    }
+
+    pub fn center(&self) -> Vector2 {
+        return Vector2 { x: self.x + self.width / 2.0, y: self.y + self.height / 2.0 };
+    }
+
+    pub fn contains_point(&self, point: Vector2) -> bool {
+        let result;
+
+        unsafe {
+            result = CheckCollisionPointRec(point.to_cvector2(), self.to_crectangle());
+        }
+
+        return result;
+    }
+
+    pub fn intersects(&self, other: Rectangle) -> bool {
+        let result;
+
+        unsafe {
+            result = CheckCollisionRecs(self.to_crectangle(), other.to_crectangle());
+        }
+
+        return result;
+    }
+
+    // The overlapping region, or None if the rectangles don't touch.
+    pub fn intersection(&self, other: Rectangle) -> Option<Rectangle> {
+        if !self.intersects(other) {
+            return None;
+        }
+
+        let result;
+
+        unsafe {
+            result = GetCollisionRec(self.to_crectangle(), other.to_crectangle());
+        }
+
+        return Some(Rectangle::from_crectangle(result));
+    }
+
+    // The smallest rectangle containing both.
+    pub fn union(&self, other: Rectangle) -> Rectangle {
+        let x = f32::min(self.x, other.x);
+        let y = f32::min(self.y, other.y);
+        let right = f32::max(self.x + self.width, other.x + other.width);
+        let bottom = f32::max(self.y + self.height, other.y + other.height);
+
+        return Rectangle { x, y, width: right - x, height: bottom - y };
+    }
+
+    // Grows the rectangle by amount on every side, keeping the same center.
+    pub fn expand(&self, amount: f32) -> Rectangle {
+        return Rectangle {
+            x: self.x - amount,
+            y: self.y - amount,
+            width: self.width + 2.0 * amount,
+            height: self.height + 2.0 * amount,
+        };
+    }
+
+    // Never shrinks past zero width or height.
+    pub fn shrink(&self, amount: f32) -> Rectangle {
+        let amount_x = f32::min(amount, self.width / 2.0);
+        let amount_y = f32::min(amount, self.height / 2.0);
+
+        return Rectangle {
+            x: self.x + amount_x,
+            y: self.y + amount_y,
+            width: self.width - 2.0 * amount_x,
+            height: self.height - 2.0 * amount_y,
+        };
+    }
+}
+
+// Raylib keeps all of its window state in globals, so only one window may be open at a time.
+static WINDOW_OPEN: AtomicBool = AtomicBool::new(false);
+
+// Shared by a WindowContext and its WindowHandles. The window closes once the last of them is dropped.
+struct OpenWindow;
+
+impl Drop for OpenWindow {
+    fn drop(&mut self) {
+        unsafe{
+            CloseWindow();
+        }
+
+        WINDOW_OPEN.store(false, Ordering::SeqCst);
+    }
+}
+
+pub struct WindowContext {
+    // Rc also keeps it from being Send or Sync: raylib (and GLFW under it) must be driven from the thread that opened the window.
+    window: Rc<OpenWindow>,
+}
+
+// Loads resources that live on the GPU (textures, models, shaders, ...). They borrow the handle that loaded them,
+// so they can't be unloaded after the window has closed, while the WindowContext itself stays free to be
+// borrowed mutably for a frame.
+#[derive(Clone)]
+pub struct WindowHandle {
+    _window: Rc<OpenWindow>,
+}
+
+impl WindowContext {
+    pub fn handle(&self) -> WindowHandle {
+        return WindowHandle { _window: Rc::clone(&self.window) };
+    }
+
+    pub fn window_should_close(&self) -> bool {
+        let flag;
+
+        unsafe {
+            flag = WindowShouldClose();
+        }
+
+        return flag;
+    }
+
+    pub fn toggle_fullscreen(&mut self) {
+        unsafe {
+            ToggleFullscreen();
+        }
+    }
+
+    pub fn toggle_borderless_windowed(&mut self) {
+        unsafe {
+            ToggleBorderlessWindowed();
+        }
+    }
+
+    pub fn maximize(&mut self) {
+        unsafe {
+            MaximizeWindow();
+        }
+    }
+
+    pub fn minimize(&mut self) {
+        unsafe {
+            MinimizeWindow();
+        }
+    }
+
+    pub fn restore(&mut self) {
+        unsafe {
+            RestoreWindow();
+        }
+    }
+
+    pub fn focus(&mut self) {
+        unsafe {
+            SetWindowFocused();
+        }
+    }
+
+    // Panics if title contains a NUL byte. See try_set_title.
+    pub fn set_title(&mut self, title: &str) {
+        self.try_set_title(title).expect("Failed to create CString.");
+    }
+
+    pub fn try_set_title(&mut self, title: &str) -> Result<(), Error> {
+        let converted_title = CString::new(title)?;
+
+        unsafe {
+            SetWindowTitle(converted_title.as_ptr());
+        }
+
+        return Ok(());
+    }
+
+    pub fn set_position(&mut self, x: i32, y: i32) {
+        unsafe {
+            SetWindowPosition(x as c_int, y as c_int);
+        }
+    }
+
+    pub fn set_size(&mut self, width: i32, height: i32) {
+        unsafe {
+            SetWindowSize(width as c_int, height as c_int);
+        }
+    }
+
+    pub fn set_min_size(&mut self, width: i32, height: i32) {
+        unsafe {
+            SetWindowMinSize(width as c_int, height as c_int);
+        }
+    }
+
+    pub fn set_max_size(&mut self, width: i32, height: i32) {
+        unsafe {
+            SetWindowMaxSize(width as c_int, height as c_int);
+        }
+    }
+
+    // Opacity is clamped by raylib to [0.0, 1.0].
+    pub fn set_opacity(&mut self, opacity: f32) {
+        unsafe {
+            SetWindowOpacity(opacity as c_float);
+        }
+    }
+
+    pub fn set_monitor(&mut self, monitor: i32) {
+        unsafe {
+            SetWindowMonitor(monitor as c_int);
+        }
+    }
+
+    pub fn set_state(&mut self, flag: ConfigFlag) {
+        unsafe {
+            SetWindowState(flag.to_flag_code());
+        }
+    }
+
+    pub fn clear_state(&mut self, flag: ConfigFlag) {
+        unsafe {
+            ClearWindowState(flag.to_flag_code());
+        }
+    }
+
+    pub fn is_window_resized(&self) -> bool {
+        let result;
+
+        unsafe {
+            result = IsWindowResized();
+        }
+
+        return result;
+    }
+
+    pub fn is_window_focused(&self) -> bool {
+        let result;
+
+        unsafe {
+            result = IsWindowFocused();
+        }
+
+        return result;
+    }
+
+    pub fn is_window_hidden(&self) -> bool {
+        let result;
+
+        unsafe {
+            result = IsWindowHidden();
+        }
+
+        return result;
+    }
+
+    pub fn is_window_minimized(&self) -> bool {
+        let result;
+
+        unsafe {
+            result = IsWindowMinimized();
+        }
+
+        return result;
+    }
+
+    pub fn is_window_maximized(&self) -> bool {
+        let result;
+
+        unsafe {
+            result = IsWindowMaximized();
+        }
+
+        return result;
+    }
+
+    pub fn is_window_fullscreen(&self) -> bool {
+        let result;
+
+        unsafe {
+            result = IsWindowFullscreen();
+        }
+
+        return result;
+    }
+
+    pub fn is_window_state(&self, flag: ConfigFlag) -> bool {
+        let result;
+
+        unsafe {
+            result = IsWindowState(flag.to_flag_code());
+        }
+
+        return result;
+    }
+
+    pub fn get_window_position(&self) -> Vector2 {
+        let result;
+
+        unsafe {
+            result = GetWindowPosition();
+        }
+
+        return Vector2 { x: result.x, y: result.y };
+    }
+
+    pub fn get_monitor_count(&self) -> i32 {
+        let result;
+
+        unsafe {
+            result = GetMonitorCount();
+        }
+
+        return result;
+    }
+
+    pub fn get_current_monitor(&self) -> i32 {
+        let result;
+
+        unsafe {
+            result = GetCurrentMonitor();
+        }
+
+        return result;
+    }
+
+    pub fn is_key_down(&self, key: Key) -> bool {
+        let flag;
+        let converted_key = key.to_key_code();
+
+        unsafe {
+            flag = IsKeyDown(converted_key);
+        }
+
+        return flag;
+    }
+
+    // Takes the next character from this frame's queue of typed characters.
+    pub fn get_char_pressed(&self) -> Option<char> {
+        loop {
+            let result;
+
+            unsafe {
+                result = GetCharPressed();
+            }
+
+            if result == 0 {
+                return None;
+            }
+
+            // Skip anything that isn't a valid Unicode scalar value rather than ending the queue early.
+            if let Some(c) = char::from_u32(result as u32) {
+                return Some(c);
+            }
+        }
+    }
+
+    // Drains every character typed this frame, in order.
+    pub fn chars_pressed(&self) -> impl Iterator<Item = char> + '_ {
+        return std::iter::from_fn(move || self.get_char_pressed());
+    }
+
+    pub fn is_key_pressed(&self, key: Key) -> bool {
+        let result;
+        let converted_key = key.to_key_code();
+
+        unsafe {
+            result = IsKeyPressed(converted_key);
+        }
+
+        return result;
+    }
+
+    pub fn is_key_pressed_repeat(&self, key: Key) -> bool {
+        let result;
+        let converted_key = key.to_key_code();
+
+        unsafe {
+            result = IsKeyPressedRepeat(converted_key);
+        }
+
+        return result;
+    }
+
+    pub fn get_delta_time(&self) -> f32 {
+        let out;
+
+        unsafe {
+            out = GetFrameTime();
+        }
+
+        return out as f32;
+    }
+
+    pub fn get_mouse_position(&self) -> Vector2 {
+        let out;
+
+        unsafe {
+            out = GetMousePosition();
+        }
+
+        let converted_out = Vector2 {x: out.x, y: out.y};
+
+        return converted_out;
+    }
+
+    pub fn is_mouse_button_pressed(&self, button: MouseButton) -> bool {
+        let result;
+
+        let converted_button = button.to_button_code();
+
+        unsafe {
+            result = IsMouseButtonPressed(converted_button);
+        }
+
+        return result;
+    }
+
+    pub fn get_screen_width(&self) -> i32 {
+        let result;
+
+        unsafe {
+            result = GetScreenWidth();
+        }
+
+        return result;
+    }
+
+    pub fn get_screen_height(&self) -> i32 {
+        let result;
+
+        unsafe {
+            result = GetScreenHeight();
+        }
+
+        return result;
+    }
+
+    pub fn get_fps(&self) -> i32 {
+        let result;
+
+        unsafe {
+            result = GetFPS();
+        }
+
+        return result;
+    }
+
+    pub fn init_drawing_context(&mut self) -> DrawingContext<'_> {
+        unsafe {
+            BeginDrawing();
+        }
+
+        return DrawingContext { window: self, render_target: None, camera: None, shader: None, blend_mode: None, scissor_area: None };
+    }
+}
+
+// Borrows the window mutably so it cannot be closed, or have its state changed, mid-frame.
+// Read-only queries on the window are still reachable through Deref.
+pub struct DrawingContext<'w> {
+    window: &'w mut WindowContext,
+    // Whatever the innermost mode guard set up, so a nested guard can put it back when it ends.
+    render_target: Option<CRenderTexture2D>,
+    camera: Option<ActiveCamera>,
+    shader: Option<CShader>,
+    blend_mode: Option<BlendMode>,
+    scissor_area: Option<Rectangle>,
+}
+
+impl<'w> Deref for DrawingContext<'w> {
+    type Target = WindowContext;
+
+    fn deref(&self) -> &WindowContext {
+        return self.window;
+    }
+}
+
+impl<'w> DrawingContext<'w> {
+    pub fn clear_background(&mut self, color: Color) {
+        unsafe {
+            ClearBackground(color.to_ccolor());
+        }
+    }
+
+    // Panics if text contains a NUL byte. See try_draw_text.
+    pub fn draw_text(&mut self, text: &str, pos_x: i32, pos_y: i32, font_size: i32, color: Color) {
+        self.try_draw_text(text, pos_x, pos_y, font_size, color).expect("Failed to create CString.");
+    }
+
+    pub fn try_draw_text(&mut self, text: &str, pos_x: i32, pos_y: i32, font_size: i32, color: Color) -> Result<(), Error> {
+        let converted_text = CString::new(text)?;
+        let text_pointer = converted_text.as_ptr();
+
+        let converted_color = color.to_ccolor();
+
+        unsafe {
+            DrawText(text_pointer, pos_x as c_int, pos_y as c_int, font_size as c_int, converted_color);
+        }
+
+        return Ok(());
+    }
+
+    // Panics if text contains a NUL byte. See try_draw_text_ex.
+    pub fn draw_text_ex(&mut self, font: &Font, text: &str, position: Vector2, font_size: f32, spacing: f32, tint: Color) {
+        self.try_draw_text_ex(font, text, position, font_size, spacing, tint).expect("Failed to create CString.");
+    }
+
+    pub fn try_draw_text_ex(&mut self, font: &Font, text: &str, position: Vector2, font_size: f32, spacing: f32, tint: Color) -> Result<(), Error> {
+        let converted_text = CString::new(text)?;
+
+        unsafe {
+            DrawTextEx(font.to_cfont(), converted_text.as_ptr(), position.to_cvector2(), font_size as c_float, spacing as c_float, tint.to_ccolor());
+        }
+
+        return Ok(());
+    }
+
+    // Rotates rotation degrees around origin, which is relative to position.
+    // Both take the same arguments as raylib's DrawTextPro.
+    // Panics if text contains a NUL byte. See try_draw_text_pro.
+    #[allow(clippy::too_many_arguments)]
+    pub fn draw_text_pro(&mut self, font: &Font, text: &str, position: Vector2, origin: Vector2, rotation: f32, font_size: f32, spacing: f32, tint: Color) {
+        self.try_draw_text_pro(font, text, position, origin, rotation, font_size, spacing, tint).expect("Failed to create CString.");
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn try_draw_text_pro(&mut self, font: &Font, text: &str, position: Vector2, origin: Vector2, rotation: f32, font_size: f32, spacing: f32, tint: Color) -> Result<(), Error> {
+        let converted_text = CString::new(text)?;
+
+        unsafe {
+            DrawTextPro(font.to_cfont(), converted_text.as_ptr(), position.to_cvector2(), origin.to_cvector2(), rotation as c_float, font_size as c_float, spacing as c_float, tint.to_ccolor());
+        }
+
+        return Ok(());
+    }
+
+    pub fn draw_text_codepoint(&mut self, font: &Font, codepoint: char, position: Vector2, font_size: f32, tint: Color) {
+        unsafe {
+            DrawTextCodepoint(font.to_cfont(), codepoint as c_int, position.to_cvector2(), font_size as c_float, tint.to_ccolor());
+        }
+    }
+
+    pub fn draw_text_codepoints(&mut self, font: &Font, codepoints: &[char], position: Vector2, font_size: f32, spacing: f32, tint: Color) {
+        let converted_codepoints: Vec<c_int> = codepoints.iter().map(|c| *c as c_int).collect();
+
+        unsafe {
+            DrawTextCodepoints(font.to_cfont(), converted_codepoints.as_ptr(), converted_codepoints.len() as c_int, position.to_cvector2(), font_size as c_float, spacing as c_float, tint.to_ccolor());
+        }
+    }
+
+    pub fn draw_rectangle(&mut self, pos_x: i32, pos_y: i32, width: i32, height: i32, color: Color) {
+        let converted_color = color.to_ccolor();
+
+        unsafe {
+            DrawRectangle(pos_x as c_int, pos_y as c_int, width as c_int, height as c_int, converted_color);
+        }
+    }
+
+    pub fn draw_circle(&mut self, center_x: i32, center_y: i32, radius: f32, color: Color) {
+        let converted_color = color.to_ccolor();
+
+        unsafe {
+            DrawCircle(center_x as c_int, center_y as c_int, radius as c_float, converted_color);
+        }
+    }
+
+    // Everything drawn through the returned context lands on target instead of the screen.
+    // Dropping the context goes back to the outer target, if texture modes are nested.
+    pub fn begin_texture_mode<'a>(&'a mut self, target: &'a mut RenderTexture2D<'_>) -> TextureModeContext<'a, 'w> {
+        let converted_target = target.to_crender_texture();
+
+        unsafe {
+            BeginTextureMode(converted_target.clone());
+        }
+
+        // BeginTextureMode resets the camera transform, so the outer camera is re-applied on drop.
+        let outer_target = self.render_target.replace(converted_target);
+        let outer_camera = self.camera.take();
+
+        return TextureModeContext { drawing: self, outer_target, outer_camera, _target: PhantomData };
+    }
+
+    // Dropping the context goes back to the outer camera, if camera modes are nested.
+    pub fn begin_mode_2d(&mut self, camera: Camera2D) -> Mode2DContext<'_, 'w> {
+        unsafe {
+            BeginMode2D(camera.to_ccamera2d());
+        }
+
+        let outer_camera = self.camera.replace(ActiveCamera::Mode2D(camera));
+
+        return Mode2DContext { drawing: self, outer_camera };
+    }
+
+    // Dropping the context goes back to the outer camera, if camera modes are nested.
+    pub fn begin_mode_3d(&mut self, camera: Camera3D) -> Mode3DContext<'_, 'w> {
+        unsafe {
+            BeginMode3D(camera.to_ccamera3d());
+        }
+
+        let outer_camera = self.camera.replace(ActiveCamera::Mode3D(camera));
+
+        return Mode3DContext { drawing: self, camera, outer_camera };
+    }
+
+    // Dropping the context goes back to the outer shader, if shader modes are nested.
+    pub fn begin_shader_mode<'a>(&'a mut self, shader: &'a Shader<'w>) -> ShaderModeContext<'a, 'w> {
+        unsafe {
+            BeginShaderMode(shader.to_cshader());
+        }
+
+        let outer_shader = self.shader.replace(shader.to_cshader());
+
+        return ShaderModeContext { drawing: self, outer_shader, _shader: shader };
+    }
+
+    // Dropping the context goes back to the outer blend mode, if blend modes are nested.
+    pub fn begin_blend_mode(&mut self, mode: BlendMode) -> BlendModeContext<'_, 'w> {
+        mode.apply();
+
+        let outer_mode = self.blend_mode.replace(mode);
+
+        return BlendModeContext { drawing: self, outer_mode };
+    }
+
+    // Only the part of the screen inside area is drawn to until the guard is dropped.
+    // A nested area replaces the outer one rather than being clipped to it, and the outer one comes back on drop.
+    pub fn begin_scissor_mode(&mut self, area: Rectangle) -> ScissorModeContext<'_, 'w> {
+        unsafe {
+            BeginScissorMode(area.x as c_int, area.y as c_int, area.width as c_int, area.height as c_int);
+        }
+
+        let outer_area = self.scissor_area.replace(area);
+
+        return ScissorModeContext { drawing: self, outer_area };
+    }
+
+    pub fn draw_texture(&mut self, texture: &Texture2D, pos_x: i32, pos_y: i32, tint: Color) {
+        unsafe {
+            DrawTexture(texture.to_ctexture(), pos_x as c_int, pos_y as c_int, tint.to_ccolor());
+        }
+    }
+
+    // Rotation is in degrees.
+    pub fn draw_texture_ex(&mut self, texture: &Texture2D, position: Vector2, rotation: f32, scale: f32, tint: Color) {
+        unsafe {
+            DrawTextureEx(texture.to_ctexture(), position.to_cvector2(), rotation as c_float, scale as c_float, tint.to_ccolor());
+        }
+    }
+
+    pub fn draw_texture_rec(&mut self, texture: &Texture2D, source: Rectangle, position: Vector2, tint: Color) {
+        unsafe {
+            DrawTextureRec(texture.to_ctexture(), source.to_crectangle(), position.to_cvector2(), tint.to_ccolor());
+        }
+    }
+
+    // Origin is relative to dest, and is what the texture rotates around.
+    pub fn draw_texture_pro(&mut self, texture: &Texture2D, source: Rectangle, dest: Rectangle, origin: Vector2, rotation: f32, tint: Color) {
+        unsafe {
+            DrawTexturePro(texture.to_ctexture(), source.to_crectangle(), dest.to_crectangle(), origin.to_cvector2(), rotation as c_float, tint.to_ccolor());
+        }
+    }
+
+    pub fn draw_line_ex(&mut self, start_pos: Vector2, end_pos: Vector2, thickness: f32, color: Color) {
+        let color = color.to_ccolor();
+        let start_pos = start_pos.to_cvector2();
+        let end_pos = end_pos.to_cvector2();
+        
+        unsafe {
+            DrawLineEx(start_pos, end_pos, thickness as c_float, color);
+        }
+    }
+
+    pub fn draw_pixel(&mut self, pos_x: i32, pos_y: i32, color: Color) {
+        unsafe {
+            DrawPixel(pos_x as c_int, pos_y as c_int, color.to_ccolor());
+        }
+    }
+
+    pub fn draw_pixel_v(&mut self, position: Vector2, color: Color) {
+        unsafe {
+            DrawPixelV(position.to_cvector2(), color.to_ccolor());
+        }
+    }
+
+    pub fn draw_line(&mut self, start_pos_x: i32, start_pos_y: i32, end_pos_x: i32, end_pos_y: i32, color: Color) {
+        unsafe {
+            DrawLine(start_pos_x as c_int, start_pos_y as c_int, end_pos_x as c_int, end_pos_y as c_int, color.to_ccolor());
+        }
+    }
+
+    pub fn draw_line_v(&mut self, start_pos: Vector2, end_pos: Vector2, color: Color) {
+        unsafe {
+            DrawLineV(start_pos.to_cvector2(), end_pos.to_cvector2(), color.to_ccolor());
+        }
+    }
+
+    pub fn draw_line_strip(&mut self, points: &[Vector2], color: Color) {
+        let converted_points: Vec<CVector2> = points.iter().map(|p| p.to_cvector2()).collect();
+
+        unsafe {
+            DrawLineStrip(converted_points.as_ptr(), converted_points.len() as c_int, color.to_ccolor());
+        }
+    }
+
+    // Cubic-bezier in-out easing between the two points.
+    pub fn draw_line_bezier(&mut self, start_pos: Vector2, end_pos: Vector2, thickness: f32, color: Color) {
+        unsafe {
+            DrawLineBezier(start_pos.to_cvector2(), end_pos.to_cvector2(), thickness as c_float, color.to_ccolor());
+        }
+    }
+
+    pub fn draw_circle_v(&mut self, center: Vector2, radius: f32, color: Color) {
+        unsafe {
+            DrawCircleV(center.to_cvector2(), radius as c_float, color.to_ccolor());
+        }
+    }
+
+    // Angles are in degrees.
+    pub fn draw_circle_sector(&mut self, center: Vector2, radius: f32, start_angle: f32, end_angle: f32, segments: i32, color: Color) {
+        unsafe {
+            DrawCircleSector(center.to_cvector2(), radius as c_float, start_angle as c_float, end_angle as c_float, segments as c_int, color.to_ccolor());
+        }
+    }
+
+    pub fn draw_circle_sector_lines(&mut self, center: Vector2, radius: f32, start_angle: f32, end_angle: f32, segments: i32, color: Color) {
+        unsafe {
+            DrawCircleSectorLines(center.to_cvector2(), radius as c_float, start_angle as c_float, end_angle as c_float, segments as c_int, color.to_ccolor());
+        }
+    }
+
+    pub fn draw_circle_gradient(&mut self, center_x: i32, center_y: i32, radius: f32, inner: Color, outer: Color) {
+        unsafe {
+            DrawCircleGradient(center_x as c_int, center_y as c_int, radius as c_float, inner.to_ccolor(), outer.to_ccolor());
+        }
+    }
+
+    pub fn draw_circle_lines(&mut self, center_x: i32, center_y: i32, radius: f32, color: Color) {
+        unsafe {
+            DrawCircleLines(center_x as c_int, center_y as c_int, radius as c_float, color.to_ccolor());
+        }
+    }
+
+    pub fn draw_circle_lines_v(&mut self, center: Vector2, radius: f32, color: Color) {
+        unsafe {
+            DrawCircleLinesV(center.to_cvector2(), radius as c_float, color.to_ccolor());
+        }
+    }
+
+    pub fn draw_ellipse(&mut self, center_x: i32, center_y: i32, radius_h: f32, radius_v: f32, color: Color) {
+        unsafe {
+            DrawEllipse(center_x as c_int, center_y as c_int, radius_h as c_float, radius_v as c_float, color.to_ccolor());
+        }
+    }
+
+    pub fn draw_ellipse_lines(&mut self, center_x: i32, center_y: i32, radius_h: f32, radius_v: f32, color: Color) {
+        unsafe {
+            DrawEllipseLines(center_x as c_int, center_y as c_int, radius_h as c_float, radius_v as c_float, color.to_ccolor());
+        }
+    }
+
+    // Angles are in degrees. Both ring functions take the same arguments as raylib's DrawRing.
+    #[allow(clippy::too_many_arguments)]
+    pub fn draw_ring(&mut self, center: Vector2, inner_radius: f32, outer_radius: f32, start_angle: f32, end_angle: f32, segments: i32, color: Color) {
+        unsafe {
+            DrawRing(center.to_cvector2(), inner_radius as c_float, outer_radius as c_float, start_angle as c_float, end_angle as c_float, segments as c_int, color.to_ccolor());
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn draw_ring_lines(&mut self, center: Vector2, inner_radius: f32, outer_radius: f32, start_angle: f32, end_angle: f32, segments: i32, color: Color) {
+        unsafe {
+            DrawRingLines(center.to_cvector2(), inner_radius as c_float, outer_radius as c_float, start_angle as c_float, end_angle as c_float, segments as c_int, color.to_ccolor());
+        }
+    }
+
+    pub fn draw_rectangle_v(&mut self, position: Vector2, size: Vector2, color: Color) {
+        unsafe {
+            DrawRectangleV(position.to_cvector2(), size.to_cvector2(), color.to_ccolor());
+        }
+    }
+
+    pub fn draw_rectangle_rec(&mut self, rec: Rectangle, color: Color) {
+        unsafe {
+            DrawRectangleRec(rec.to_crectangle(), color.to_ccolor());
+        }
+    }
+
+    // Rotates by rotation degrees around origin, which is relative to the rectangle's position.
+    pub fn draw_rectangle_pro(&mut self, rec: Rectangle, origin: Vector2, rotation: f32, color: Color) {
+        unsafe {
+            DrawRectanglePro(rec.to_crectangle(), origin.to_cvector2(), rotation as c_float, color.to_ccolor());
+        }
+    }
+
+    pub fn draw_rectangle_gradient_v(&mut self, pos_x: i32, pos_y: i32, width: i32, height: i32, top: Color, bottom: Color) {
+        unsafe {
+            DrawRectangleGradientV(pos_x as c_int, pos_y as c_int, width as c_int, height as c_int, top.to_ccolor(), bottom.to_ccolor());
+        }
+    }
+
+    pub fn draw_rectangle_gradient_h(&mut self, pos_x: i32, pos_y: i32, width: i32, height: i32, left: Color, right: Color) {
+        unsafe {
+            DrawRectangleGradientH(pos_x as c_int, pos_y as c_int, width as c_int, height as c_int, left.to_ccolor(), right.to_ccolor());
+        }
+    }
+
+    pub fn draw_rectangle_gradient_ex(&mut self, rec: Rectangle, top_left: Color, bottom_left: Color, bottom_right: Color, top_right: Color) {
+        unsafe {
+            DrawRectangleGradientEx(rec.to_crectangle(), top_left.to_ccolor(), bottom_left.to_ccolor(), bottom_right.to_ccolor(), top_right.to_ccolor());
+        }
+    }
+
+    pub fn draw_rectangle_lines(&mut self, pos_x: i32, pos_y: i32, width: i32, height: i32, color: Color) {
+        unsafe {
+            DrawRectangleLines(pos_x as c_int, pos_y as c_int, width as c_int, height as c_int, color.to_ccolor());
+        }
+    }
+
+    pub fn draw_rectangle_lines_ex(&mut self, rec: Rectangle, thickness: f32, color: Color) {
+        unsafe {
+            DrawRectangleLinesEx(rec.to_crectangle(), thickness as c_float, color.to_ccolor());
+        }
+    }
+
+    // Roundness in [0.0, 1.0].
+    pub fn draw_rectangle_rounded(&mut self, rec: Rectangle, roundness: f32, segments: i32, color: Color) {
+        unsafe {
+            DrawRectangleRounded(rec.to_crectangle(), roundness as c_float, segments as c_int, color.to_ccolor());
+        }
+    }
+
+    pub fn draw_rectangle_rounded_lines(&mut self, rec: Rectangle, roundness: f32, segments: i32, color: Color) {
+        unsafe {
+            DrawRectangleRoundedLines(rec.to_crectangle(), roundness as c_float, segments as c_int, color.to_ccolor());
+        }
+    }
+
+    pub fn draw_rectangle_rounded_lines_ex(&mut self, rec: Rectangle, roundness: f32, segments: i32, thickness: f32, color: Color) {
+        unsafe {
+            DrawRectangleRoundedLinesEx(rec.to_crectangle(), roundness as c_float, segments as c_int, thickness as c_float, color.to_ccolor());
+        }
+    }
+
+    // Vertices must be in counter-clockwise order, or nothing is drawn.
+    pub fn draw_triangle(&mut self, v1: Vector2, v2: Vector2, v3: Vector2, color: Color) {
+        unsafe {
+            DrawTriangle(v1.to_cvector2(), v2.to_cvector2(), v3.to_cvector2(), color.to_ccolor());
+        }
+    }
+
+    pub fn draw_triangle_lines(&mut self, v1: Vector2, v2: Vector2, v3: Vector2, color: Color) {
+        unsafe {
+            DrawTriangleLines(v1.to_cvector2(), v2.to_cvector2(), v3.to_cvector2(), color.to_ccolor());
+        }
+    }
+
+    // The first point is the center of the fan.
+    pub fn draw_triangle_fan(&mut self, points: &[Vector2], color: Color) {
+        let converted_points: Vec<CVector2> = points.iter().map(|p| p.to_cvector2()).collect();
+
+        unsafe {
+            DrawTriangleFan(converted_points.as_ptr(), converted_points.len() as c_int, color.to_ccolor());
+        }
+    }
+
+    pub fn draw_triangle_strip(&mut self, points: &[Vector2], color: Color) {
+        let converted_points: Vec<CVector2> = points.iter().map(|p| p.to_cvector2()).collect();
+
+        unsafe {
+            DrawTriangleStrip(converted_points.as_ptr(), converted_points.len() as c_int, color.to_ccolor());
+        }
+    }
+
+    // Regular polygon. Rotation is in degrees.
+    pub fn draw_poly(&mut self, center: Vector2, sides: i32, radius: f32, rotation: f32, color: Color) {
+        unsafe {
+            DrawPoly(center.to_cvector2(), sides as c_int, radius as c_float, rotation as c_float, color.to_ccolor());
+        }
+    }
+
+    pub fn draw_poly_lines(&mut self, center: Vector2, sides: i32, radius: f32, rotation: f32, color: Color) {
+        unsafe {
+            DrawPolyLines(center.to_cvector2(), sides as c_int, radius as c_float, rotation as c_float, color.to_ccolor());
+        }
+    }
+
+    pub fn draw_poly_lines_ex(&mut self, center: Vector2, sides: i32, radius: f32, rotation: f32, thickness: f32, color: Color) {
+        unsafe {
+            DrawPolyLinesEx(center.to_cvector2(), sides as c_int, radius as c_float, rotation as c_float, thickness as c_float, color.to_ccolor());
+        }
+    }
+
+    // See SplineKind for how many points each kind of spline expects.
+    pub fn draw_spline(&mut self, kind: SplineKind, points: &[Vector2], thickness: f32, color: Color) {
+        let converted_points: Vec<CVector2> = points.iter().map(|p| p.to_cvector2()).collect();
+        let points_pointer = converted_points.as_ptr();
+        let point_count = converted_points.len() as c_int;
+        let color = color.to_ccolor();
+
+        unsafe {
+            match kind {
+                SplineKind::Linear => DrawSplineLinear(points_pointer, point_count, thickness as c_float, color),
+                SplineKind::Basis => DrawSplineBasis(points_pointer, point_count, thickness as c_float, color),
+                SplineKind::CatmullRom => DrawSplineCatmullRom(points_pointer, point_count, thickness as c_float, color),
+                SplineKind::BezierQuadratic => DrawSplineBezierQuadratic(points_pointer, point_count, thickness as c_float, color),
+                SplineKind::BezierCubic => DrawSplineBezierCubic(points_pointer, point_count, thickness as c_float, color),
+            }
+        }
+    }
+
+    pub fn draw_spline_linear(&mut self, points: &[Vector2], thickness: f32, color: Color) {
+        self.draw_spline(SplineKind::Linear, points, thickness, color);
+    }
+
+    pub fn draw_spline_basis(&mut self, points: &[Vector2], thickness: f32, color: Color) {
+        self.draw_spline(SplineKind::Basis, points, thickness, color);
+    }
+
+    pub fn draw_spline_catmull_rom(&mut self, points: &[Vector2], thickness: f32, color: Color) {
+        self.draw_spline(SplineKind::CatmullRom, points, thickness, color);
+    }
+
+    pub fn draw_spline_bezier_quadratic(&mut self, points: &[Vector2], thickness: f32, color: Color) {
+        self.draw_spline(SplineKind::BezierQuadratic, points, thickness, color);
+    }
+
+    pub fn draw_spline_bezier_cubic(&mut self, points: &[Vector2], thickness: f32, color: Color) {
+        self.draw_spline(SplineKind::BezierCubic, points, thickness, color);
+    }
+}
+
+impl<'w> Drop for DrawingContext<'w> {
+    fn drop(&mut self) {
+        unsafe {
+            EndDrawing();
+        }
+    }
+}
+
+pub fn init_window_context(width: i32, height: i32, title: &str) -> Result<WindowContext, Error> {
+    return WindowBuilder::new(width, height, title).build();
+}
+
+#[derive(Clone, Copy, Debug)]
+pub enum ConfigFlag {
+    VsyncHint,                  // Set to try enabling V-Sync on GPU
+    FullscreenMode,             // Set to run program in fullscreen
+    WindowResizable,            // Set to allow resizable window
+    WindowUndecorated,          // Set to disable window decoration (frame and buttons)
+    WindowHidden,               // Set to hide window
+    WindowMinimized,            // Set to minimize window (iconify)
+    WindowMaximized,            // Set to maximize window (expanded to monitor)
+    WindowUnfocused,            // Set to window non focused
+    WindowTopmost,              // Set to window always on top
+    WindowAlwaysRun,            // Set to allow windows running while minimized
+    WindowTransparent,          // Set to allow transparent framebuffer
+    WindowHighDpi,              // Set to support HighDPI
+    WindowMousePassthrough,     // Set to support mouse passthrough, only supported when WindowUndecorated
+    BorderlessWindowedMode,     // Set to run program in borderless windowed mode
+    Msaa4xHint,                 // Set to try enabling MSAA 4X
+    InterlacedHint,             // Set to try enabling interlaced video format (for V3D)
+}
+
+impl ConfigFlag {
+    fn to_flag_code(&self) -> c_uint {
+        match self {
+            ConfigFlag::VsyncHint              => 0x00000040,
+            ConfigFlag::FullscreenMode         => 0x00000002,
+            ConfigFlag::WindowResizable        => 0x00000004,
+            ConfigFlag::WindowUndecorated      => 0x00000008,
+            ConfigFlag::WindowHidden           => 0x00000080,
+            ConfigFlag::WindowMinimized        => 0x00000200,
+            ConfigFlag::WindowMaximized        => 0x00000400,
+            ConfigFlag::WindowUnfocused        => 0x00000800,
+            ConfigFlag::WindowTopmost          => 0x00001000,
+            ConfigFlag::WindowAlwaysRun        => 0x00000100,
+            ConfigFlag::WindowTransparent      => 0x00000010,
+            ConfigFlag::WindowHighDpi          => 0x00002000,
+            ConfigFlag::WindowMousePassthrough => 0x00004000,
+            ConfigFlag::BorderlessWindowedMode => 0x00008000,
+            ConfigFlag::Msaa4xHint             => 0x00000020,
+            ConfigFlag::InterlacedHint         => 0x00010000,
+        }
+    }
+}
+
+// Collects everything raylib wants to know before (or right after) InitWindow.
+// Config flags only take effect if set before the window is created.
+pub struct WindowBuilder {
+    width: i32,
+    height: i32,
+    title: String,
+    flags: c_uint,
+    min_size: Option<(i32, i32)>,
+    max_size: Option<(i32, i32)>,
+    position: Option<(i32, i32)>,
+    target_fps: Option<i32>,
+    exit_key: Option<Key>,
+}
+
+impl WindowBuilder {
+    pub fn new(width: i32, height: i32, title: &str) -> WindowBuilder {
+        return WindowBuilder {
+            width,
+            height,
+            title: title.to_string(),
+            flags: 0,
+            min_size: None,
+            max_size: None,
+            position: None,
+            target_fps: None,
+            exit_key: None,
+        };
+    }
+
+    pub fn flag(mut self, flag: ConfigFlag) -> WindowBuilder {
+        self.flags |= flag.to_flag_code();
+
+        return self;
+    }
+
+    pub fn msaa_4x(self) -> WindowBuilder {
+        return self.flag(ConfigFlag::Msaa4xHint);
+    }
+
+    pub fn vsync(self) -> WindowBuilder {
+        return self.flag(ConfigFlag::VsyncHint);
+    }
+
+    pub fn resizable(self) -> WindowBuilder {
+        return self.flag(ConfigFlag::WindowResizable);
+    }
+
+    pub fn undecorated(self) -> WindowBuilder {
+        return self.flag(ConfigFlag::WindowUndecorated);
+    }
+
+    pub fn transparent(self) -> WindowBuilder {
+        return self.flag(ConfigFlag::WindowTransparent);
+    }
+
+    pub fn high_dpi(self) -> WindowBuilder {
+        return self.flag(ConfigFlag::WindowHighDpi);
+    }
+
+    pub fn fullscreen(self) -> WindowBuilder {
+        return self.flag(ConfigFlag::FullscreenMode);
+    }
+
+    pub fn min_size(mut self, width: i32, height: i32) -> WindowBuilder {
+        self.min_size = Some((width, height));
+
+        return self;
+    }
+
+    pub fn max_size(mut self, width: i32, height: i32) -> WindowBuilder {
+        self.max_size = Some((width, height));
+
+        return self;
+    }
+
+    pub fn position(mut self, x: i32, y: i32) -> WindowBuilder {
+        self.position = Some((x, y));
+
+        return self;
+    }
+
+    pub fn target_fps(mut self, fps: i32) -> WindowBuilder {
+        self.target_fps = Some(fps);
+
+        return self;
+    }
+
+    // Key::NULL disables the exit key entirely.
+    pub fn exit_key(mut self, key: Key) -> WindowBuilder {
+        self.exit_key = Some(key);
+
+        return self;
+    }
+
+    pub fn build(self) -> Result<WindowContext, Error> {
+        if self.width < 0 || self.height < 0 {
+            return Err(Error::InvalidSize(self.width, self.height));
+        }
+
+        let converted_title = CString::new(self.title)?;
+
+        if WINDOW_OPEN.swap(true, Ordering::SeqCst) {
+            return Err(Error::WindowAlreadyOpen);
+        }
+
+        unsafe {
+            if self.flags != 0 {
+                SetConfigFlags(self.flags);
+            }
+
+            InitWindow(self.width as c_int, self.height as c_int, converted_title.as_ptr());
+
+            if !IsWindowReady() {
+                WINDOW_OPEN.store(false, Ordering::SeqCst);
+                return Err(Error::WindowInitFailed);
+            }
+
+            if let Some((width, height)) = self.min_size {
+                SetWindowMinSize(width as c_int, height as c_int);
+            }
+
+            if let Some((width, height)) = self.max_size {
+                SetWindowMaxSize(width as c_int, height as c_int);
+            }
+
+            if let Some((x, y)) = self.position {
+                SetWindowPosition(x as c_int, y as c_int);
+            }
+
+            if let Some(fps) = self.target_fps {
+                SetTargetFPS(fps as c_int);
+            }
+
+            if let Some(key) = self.exit_key {
+                SetExitKey(key.to_key_code());
+            }
+        }
+
+        return Ok(WindowContext { window: Rc::new(OpenWindow) });
+    }
+}
+
+#[derive(Debug)]
+pub enum Key {
+    NULL,                       // Key: NULL, used for no key pressed
+    // Alphanumeric keys
+    APOSTROPHE,                 // Key: '
+    COMMA,                      // Key: ,
+    MINUS,                      // Key: -
+    PERIOD,                     // Key: .
+    SLASH,                      // Key: /
+    ZERO,                       // Key: 0
+    ONE,                        // Key: 1
+    TWO,                        // Key: 2
+    THREE,                      // Key: 3
+    FOUR,                       // Key: 4
+    FIVE,                       // Key: 5
+    SIX,                        // Key: 6
+    SEVEN,                      // Key: 7
+    EIGHT,                      // Key: 8
+    NINE,                       // Key: 9
+    SEMICOLON,                  // Key: ;
+    EQUAL,                      // Key: =
+    A,                          // Key: A | a
+    B,                          // Key: B | b
+    C,                          // Key: C | c
+    D,                          // Key: D | d
+    E,                          // Key: E | e
+    F,                          // Key: F | f
+    G,                          // Key: G | g
+    H,                          // Key: H | h
+    I,                          // Key: I | i
+    J,                          // Key: J | j
+    K,                          // Key: K | k
+    L,                          // Key: L | l
+    M,                          // Key: M | m
+    N,                          // Key: N | n
+    O,                          // Key: O | o
+    P,                          // Key: P | p
+    Q,                          // Key: Q | q
+    R,                          // Key: R | r
+    S,                          // Key: S | s
+    T,                          // Key: T | t
+    U,                          // Key: U | u
+    V,                          // Key: V | v
+    W,                          // Key: W | w
+    X,                          // Key: X | x
+    Y,                          // Key: Y | y
+    Z,                          // Key: Z | z
+    LEFT_BRACKET,               // Key: [
+    BACKSLASH,                  // Key: '\'
+    RIGHT_BRACKET,              // Key: ]
+    GRAVE,                      // Key: `
+    // Function keys
+    SPACE,                      // Key: Space
+    ESCAPE,                     // Key: Esc
+    ENTER,                      // Key: Enter
+    TAB,                        // Key: Tab
+    BACKSPACE,                  // Key: Backspace
+    INSERT,                     // Key: Ins
+    DELETE,                     // Key: Del
+    RIGHT,                      // Key: Cursor right
+    LEFT,                       // Key: Cursor left
+    DOWN,                       // Key: Cursor down
+    UP,                         // Key: Cursor up
+    PAGE_UP,                    // Key: Page up
+    PAGE_DOWN,                  // Key: Page down
+    HOME,                       // Key: Home
+    END,                        // Key: End
+    CAPS_LOCK,                  // Key: Caps lock
+    SCROLL_LOCK,                // Key: Scroll down
+    NUM_LOCK,                   // Key: Num lock
+    PRINT_SCREEN,               // Key: Print screen
+    PAUSE,                      // Key: Pause
+    F1,                         // Key: F1
+    F2,                         // Key: F2
+    F3,                         // Key: F3
+    F4,                         // Key: F4
+    F5,                         // Key: F5
+    F6,                         // Key: F6
+    F7,                         // Key: F7
+    F8,                         // Key: F8
+    F9,                         // Key: F9
+    F10,                        // Key: F10
+    F11,                        // Key: F11
+    F12,                        // Key: F12
+    LEFT_SHIFT,                 // Key: Shift left
+    LEFT_CONTROL,               // Key: Control left
+    LEFT_ALT,                   // Key: Alt left
+    LEFT_SUPER,                 // Key: Super left
+    RIGHT_SHIFT,                // Key: Shift right
+    RIGHT_CONTROL,              // Key: Control right
+    RIGHT_ALT,                  // Key: Alt right
+    RIGHT_SUPER,                // Key: Super right
+    KB_MENU,                    // Key: KB menu
+    // Keypad keys
+    KP_0,                       // Key: Keypad 0
+    KP_1,                       // Key: Keypad 1
+    KP_2,                       // Key: Keypad 2
+    KP_3,                       // Key: Keypad 3
+    KP_4,                       // Key: Keypad 4
+    KP_5,                       // Key: Keypad 5
+    KP_6,                       // Key: Keypad 6
+    KP_7,                       // Key: Keypad 7
+    KP_8,                       // Key: Keypad 8
+    KP_9,                       // Key: Keypad 9
+    KP_DECIMAL,                 // Key: Keypad .
+    KP_DIVIDE,                  // Key: Keypad /
+    KP_MULTIPLY,                // Key: Keypad *
+    KP_SUBTRACT,                // Key: Keypad -
+    KP_ADD,                     // Key: Keypad +
+    KP_ENTER,                   // Key: Keypad Enter
+    KP_EQUAL,                   // Key: Keypad =
+    // Android key buttons
+    BACK,                       // Key: Android back button
+    MENU,                       // Key: Android menu button
+    VOLUME_UP,                  // Key: Android volume up button
+    VOLUME_DOWN,                // Key: Android volume down button
+}
+
+impl Key {
+    fn to_key_code(&self) -> c_int {
+        match self {
+            Key::NULL            => 0,        // Key: NULL, used for no key pressed
+            // Alphanumeric keys
+            Key::APOSTROPHE      => 39,       // Key: '
+            Key::COMMA           => 44,       // Key: ,
+            Key::MINUS           => 45,       // Key: -
+            Key::PERIOD          => 46,       // Key: .
+            Key::SLASH           => 47,       // Key: /
+            Key::ZERO            => 48,       // Key: 0
             Key::ONE             => 49,       // Key: 1
             Key::TWO             => 50,       // Key: 2
             Key::THREE           => 51,       // Key: 3
@@ -709,126 +3759,3860 @@ impl Key {
             Key::VOLUME_DOWN     => 25,       // Key: Android volume down button
         }
     }
-}
+}
+
+pub enum MouseButton {
+    Left,
+    Right,
+    Middle,
+}
+
+impl MouseButton {
+    fn to_button_code(&self) -> c_int {
+        match self {
+            MouseButton::Left => 0,
+            MouseButton::Right => 1,
+            MouseButton::Middle => 2,
+        }
+    }
+}
+
+/*===================================
+             Font stuff.
+=====================================*/
+
+// Owns its glyph data and atlas texture, and unloads them when dropped.
+pub struct Font<'w> {
+    inner: CFont,
+    _window: PhantomData<&'w WindowHandle>,
+}
+
+impl<'w> Font<'w> {
+    fn to_cfont(&self) -> CFont {
+        return self.inner.clone();
+    }
+
+    fn from_cfont(font: CFont, source: &str) -> Result<Font<'w>, Error> {
+        let valid;
+
+        // Raylib hands back its default font when loading fails, which we must not take ownership of.
+        unsafe {
+            valid = IsFontValid(font.clone()) && font.texture.id != GetFontDefault().texture.id;
+        }
+
+        if !valid {
+            return Err(Error::ResourceLoadFailed(source.to_string()));
+        }
+
+        return Ok(Font { inner: font, _window: PhantomData });
+    }
+
+    // The size glyphs were rasterized at.
+    pub fn base_size(&self) -> i32 {
+        return self.inner.base_size;
+    }
+
+    pub fn glyph_count(&self) -> i32 {
+        return self.inner.glyph_count;
+    }
+
+    pub fn glyph_padding(&self) -> i32 {
+        return self.inner.glyph_padding;
+    }
+}
+
+impl Drop for Font<'_> {
+    fn drop(&mut self) {
+        unsafe {
+            UnloadFont(self.to_cfont());
+        }
+    }
+}
+
+// Raylib's built-in font. It belongs to raylib and is freed with the window, so this handle never unloads it.
+pub struct DefaultFont<'w> {
+    font: ManuallyDrop<Font<'w>>,
+}
+
+impl<'w> Deref for DefaultFont<'w> {
+    type Target = Font<'w>;
+
+    fn deref(&self) -> &Font<'w> {
+        return &self.font;
+    }
+}
+
+impl<'w> Clone for DefaultFont<'w> {
+    fn clone(&self) -> DefaultFont<'w> {
+        return DefaultFont { font: ManuallyDrop::new(Font { inner: self.font.to_cfont(), _window: PhantomData }) };
+    }
+}
+
+fn codepoints_to_c(codepoints: Option<&[char]>) -> Option<Vec<c_int>> {
+    return codepoints.map(|codepoints| codepoints.iter().map(|c| *c as c_int).collect());
+}
+
+impl WindowHandle {
+    pub fn get_default_font(&self) -> DefaultFont<'_> {
+        let result;
+
+        unsafe {
+            result = GetFontDefault();
+        }
+
+        return DefaultFont { font: ManuallyDrop::new(Font { inner: result, _window: PhantomData }) };
+    }
+
+    // Supports .ttf, .otf, .fnt (BMFont) and font images. TTF/OTF fonts are rasterized at 32px.
+    pub fn load_font(&self, file_name: &str) -> Result<Font<'_>, Error> {
+        let converted_file_name = CString::new(file_name)?;
+        let result;
+
+        unsafe {
+            result = LoadFont(converted_file_name.as_ptr());
+        }
+
+        return Font::from_cfont(result, file_name);
+    }
+
+    // Rasterizes at font_size. codepoints picks which glyphs to load; None loads the 95 printable ASCII characters.
+    pub fn load_font_ex(&self, file_name: &str, font_size: i32, codepoints: Option<&[char]>) -> Result<Font<'_>, Error> {
+        let converted_file_name = CString::new(file_name)?;
+        let converted_codepoints = codepoints_to_c(codepoints);
+        let result;
+
+        unsafe {
+            result = LoadFontEx(
+                converted_file_name.as_ptr(),
+                font_size as c_int,
+                converted_codepoints.as_ref().map_or(std::ptr::null(), |c| c.as_ptr()),
+                converted_codepoints.as_ref().map_or(0, |c| c.len() as c_int),
+            );
+        }
+
+        return Font::from_cfont(result, file_name);
+    }
+
+    // file_type is the extension, including the dot, e.g. ".ttf". See load_font_ex for the rest.
+    pub fn load_font_from_memory(&self, file_type: &str, data: &[u8], font_size: i32, codepoints: Option<&[char]>) -> Result<Font<'_>, Error> {
+        let converted_file_type = CString::new(file_type)?;
+        let converted_codepoints = codepoints_to_c(codepoints);
+        let result;
+
+        unsafe {
+            result = LoadFontFromMemory(
+                converted_file_type.as_ptr(),
+                data.as_ptr(),
+                data.len() as c_int,
+                font_size as c_int,
+                converted_codepoints.as_ref().map_or(std::ptr::null(), |c| c.as_ptr()),
+                converted_codepoints.as_ref().map_or(0, |c| c.len() as c_int),
+            );
+        }
+
+        return Font::from_cfont(result, file_type);
+    }
+
+    // Loads glyphs for printable ASCII plus every character used in text, e.g. all of a game's localized strings.
+    pub fn load_font_for_text(&self, file_name: &str, font_size: i32, text: &str) -> Result<Font<'_>, Error> {
+        let mut codepoints: Vec<char> = (' '..='~').chain(text.chars().filter(|c| !c.is_control())).collect();
+        codepoints.sort_unstable();
+        codepoints.dedup();
+
+        return self.load_font_ex(file_name, font_size, Some(&codepoints));
+    }
+
+    // Glyphs in image are separated by key colored pixels, in codepoint order starting at first_char.
+    pub fn load_font_from_image(&self, image: &Image, key: Color, first_char: char) -> Result<Font<'_>, Error> {
+        let result;
+
+        unsafe {
+            result = LoadFontFromImage(image.to_cimage(), key.to_ccolor(), first_char as c_int);
+        }
+
+        return Font::from_cfont(result, "font image");
+    }
+}
+
+impl WindowContext {
+    // Width in pixels of text drawn with the default font, as by DrawingContext::draw_text.
+    // Needs the window, since the default font only exists while it is open.
+    // Panics if text contains a NUL byte. See try_measure_text.
+    pub fn measure_text(&self, text: &str, font_size: i32) -> i32 {
+        return self.try_measure_text(text, font_size).expect("Failed to create CString.");
+    }
+
+    pub fn try_measure_text(&self, text: &str, font_size: i32) -> Result<i32, Error> {
+        let converted_text = CString::new(text)?;
+        let result;
+
+        unsafe {
+            result = MeasureText(converted_text.as_ptr(), font_size as c_int);
+        }
+
+        return Ok(result);
+    }
+
+    // Panics if text contains a NUL byte. See try_measure_text_ex.
+    pub fn measure_text_ex(&self, font: &Font, text: &str, font_size: f32, spacing: f32) -> Vector2 {
+        return self.try_measure_text_ex(font, text, font_size, spacing).expect("Failed to create CString.");
+    }
+
+    pub fn try_measure_text_ex(&self, font: &Font, text: &str, font_size: f32, spacing: f32) -> Result<Vector2, Error> {
+        let converted_text = CString::new(text)?;
+        let text_pointer = converted_text.as_ptr();
+        let result: CVector2;
+
+        unsafe {
+            result = MeasureTextEx(font.to_cfont(), text_pointer, font_size as c_float, spacing as c_float);
+        }
+
+        return Ok(Vector2 { x: result.x, y: result.y });
+    }
+}
+
+pub fn set_text_line_spacing(spacing: i32) {
+    unsafe {
+        SetTextLineSpacing(spacing as c_int);
+    }
+}
+
+/*===================================
+        Images and textures.
+=====================================*/
+
+// Images live in CPU memory and don't need a window.
+pub struct Image {
+    inner: CImage,
+}
+
+impl Image {
+    fn to_cimage(&self) -> CImage {
+        return self.inner.clone();
+    }
+
+    fn from_cimage(image: CImage, source: &str) -> Result<Image, Error> {
+        let valid;
+
+        unsafe {
+            valid = IsImageValid(image.clone());
+        }
+
+        if !valid {
+            return Err(Error::ResourceLoadFailed(source.to_string()));
+        }
+
+        return Ok(Image { inner: image });
+    }
+
+    pub fn load(file_name: &str) -> Result<Image, Error> {
+        let converted_file_name = CString::new(file_name)?;
+        let result;
+
+        unsafe {
+            result = LoadImage(converted_file_name.as_ptr());
+        }
+
+        return Image::from_cimage(result, file_name);
+    }
+
+    // file_type is the extension, including the dot, e.g. ".png".
+    pub fn load_from_memory(file_type: &str, data: &[u8]) -> Result<Image, Error> {
+        let converted_file_type = CString::new(file_type)?;
+        let result;
+
+        unsafe {
+            result = LoadImageFromMemory(converted_file_type.as_ptr(), data.as_ptr(), data.len() as c_int);
+        }
+
+        return Image::from_cimage(result, file_type);
+    }
+
+    pub fn gen_color(width: i32, height: i32, color: Color) -> Result<Image, Error> {
+        if width < 0 || height < 0 {
+            return Err(Error::InvalidSize(width, height));
+        }
+
+        let result;
+
+        unsafe {
+            result = GenImageColor(width as c_int, height as c_int, color.to_ccolor());
+        }
+
+        return Ok(Image { inner: result });
+    }
+
+    // Copies the region of this image covered by rec into a new image.
+    // Raylib doesn't bounds-check rec here, so it's clamped to the image first, the way crop does.
+    pub fn from_image(&self, rec: Rectangle) -> Image {
+        let x = (rec.x as i32).clamp(0, self.width());
+        let y = (rec.y as i32).clamp(0, self.height());
+        let right = ((rec.x + rec.width) as i32).clamp(x, self.width());
+        let bottom = ((rec.y + rec.height) as i32).clamp(y, self.height());
+        let clamped = Rectangle::new(x as f32, y as f32, (right - x) as f32, (bottom - y) as f32);
+
+        let result;
+
+        unsafe {
+            result = ImageFromImage(self.to_cimage(), clamped.to_crectangle());
+        }
+
+        return Image { inner: result };
+    }
+
+    pub fn width(&self) -> i32 {
+        return self.inner.width;
+    }
+
+    pub fn height(&self) -> i32 {
+        return self.inner.height;
+    }
+
+    pub fn format(&self) -> PixelFormat {
+        return PixelFormat::from_format_code(self.inner.format);
+    }
+
+    // Only the first mipmap level is exposed.
+    // Returns None unless the image is in PixelFormat::UncompressedR8G8B8A8, see set_format.
+    pub fn pixels(&self) -> Option<&[Color]> {
+        if self.format() != PixelFormat::UncompressedR8G8B8A8 || self.inner.data.is_null() {
+            return None;
+        }
+
+        let len = (self.inner.width * self.inner.height) as usize;
+
+        unsafe {
+            return Some(slice::from_raw_parts(self.inner.data as *const Color, len));
+        }
+    }
+
+    pub fn pixels_mut(&mut self) -> Option<&mut [Color]> {
+        if self.format() != PixelFormat::UncompressedR8G8B8A8 || self.inner.data.is_null() {
+            return None;
+        }
+
+        let len = (self.inner.width * self.inner.height) as usize;
+
+        unsafe {
+            return Some(slice::from_raw_parts_mut(self.inner.data as *mut Color, len));
+        }
+    }
+
+    // Works for any pixel format, unlike pixels.
+    pub fn get_color(&self, x: i32, y: i32) -> Color {
+        let result;
+
+        unsafe {
+            result = GetImageColor(self.to_cimage(), x as c_int, y as c_int);
+        }
+
+        return Color { r: result.r, g: result.g, b: result.b, a: result.a };
+    }
+
+    pub fn set_format(&mut self, format: PixelFormat) {
+        unsafe {
+            ImageFormat(&mut self.inner, format.to_format_code());
+        }
+    }
+
+    pub fn crop(&mut self, crop: Rectangle) {
+        unsafe {
+            ImageCrop(&mut self.inner, crop.to_crectangle());
+        }
+    }
+
+    // Bilinear filtering.
+    pub fn resize(&mut self, new_width: i32, new_height: i32) -> Result<(), Error> {
+        if new_width < 0 || new_height < 0 {
+            return Err(Error::InvalidSize(new_width, new_height));
+        }
+
+        unsafe {
+            ImageResize(&mut self.inner, new_width as c_int, new_height as c_int);
+        }
+
+        return Ok(());
+    }
+
+    // Nearest-neighbour filtering, for pixel art.
+    pub fn resize_nn(&mut self, new_width: i32, new_height: i32) -> Result<(), Error> {
+        if new_width < 0 || new_height < 0 {
+            return Err(Error::InvalidSize(new_width, new_height));
+        }
+
+        unsafe {
+            ImageResizeNN(&mut self.inner, new_width as c_int, new_height as c_int);
+        }
+
+        return Ok(());
+    }
+
+    pub fn resize_canvas(&mut self, new_width: i32, new_height: i32, offset_x: i32, offset_y: i32, fill: Color) -> Result<(), Error> {
+        if new_width < 0 || new_height < 0 {
+            return Err(Error::InvalidSize(new_width, new_height));
+        }
+
+        unsafe {
+            ImageResizeCanvas(&mut self.inner, new_width as c_int, new_height as c_int, offset_x as c_int, offset_y as c_int, fill.to_ccolor());
+        }
+
+        return Ok(());
+    }
+
+    pub fn flip_vertical(&mut self) {
+        unsafe {
+            ImageFlipVertical(&mut self.inner);
+        }
+    }
+
+    pub fn flip_horizontal(&mut self) {
+        unsafe {
+            ImageFlipHorizontal(&mut self.inner);
+        }
+    }
+
+    // Degrees, anywhere in [-359, 359]. Grows the image to fit.
+    pub fn rotate(&mut self, degrees: i32) {
+        unsafe {
+            ImageRotate(&mut self.inner, degrees as c_int);
+        }
+    }
+
+    pub fn rotate_cw(&mut self) {
+        unsafe {
+            ImageRotateCW(&mut self.inner);
+        }
+    }
+
+    pub fn rotate_ccw(&mut self) {
+        unsafe {
+            ImageRotateCCW(&mut self.inner);
+        }
+    }
+
+    pub fn color_tint(&mut self, color: Color) {
+        unsafe {
+            ImageColorTint(&mut self.inner, color.to_ccolor());
+        }
+    }
+
+    pub fn color_invert(&mut self) {
+        unsafe {
+            ImageColorInvert(&mut self.inner);
+        }
+    }
+
+    pub fn color_grayscale(&mut self) {
+        unsafe {
+            ImageColorGrayscale(&mut self.inner);
+        }
+    }
+
+    // Contrast in [-100, 100].
+    pub fn color_contrast(&mut self, contrast: f32) {
+        unsafe {
+            ImageColorContrast(&mut self.inner, contrast as c_float);
+        }
+    }
+
+    // Brightness in [-255, 255].
+    pub fn color_brightness(&mut self, brightness: i32) {
+        unsafe {
+            ImageColorBrightness(&mut self.inner, brightness as c_int);
+        }
+    }
+
+    pub fn color_replace(&mut self, color: Color, replace: Color) {
+        unsafe {
+            ImageColorReplace(&mut self.inner, color.to_ccolor(), replace.to_ccolor());
+        }
+    }
+
+    // Crops away the border where alpha is at or below threshold, in [0.0, 1.0].
+    pub fn alpha_crop(&mut self, threshold: f32) {
+        unsafe {
+            ImageAlphaCrop(&mut self.inner, threshold as c_float);
+        }
+    }
+
+    pub fn alpha_clear(&mut self, color: Color, threshold: f32) {
+        unsafe {
+            ImageAlphaClear(&mut self.inner, color.to_ccolor(), threshold as c_float);
+        }
+    }
+
+    // alpha_mask should be the same size as this image. Its grayscale values become the alpha channel.
+    pub fn alpha_mask(&mut self, alpha_mask: &Image) {
+        unsafe {
+            ImageAlphaMask(&mut self.inner, alpha_mask.to_cimage());
+        }
+    }
+
+    pub fn alpha_premultiply(&mut self) {
+        unsafe {
+            ImageAlphaPremultiply(&mut self.inner);
+        }
+    }
+
+    pub fn clear_background(&mut self, color: Color) {
+        unsafe {
+            ImageClearBackground(&mut self.inner, color.to_ccolor());
+        }
+    }
+
+    pub fn draw_pixel(&mut self, pos_x: i32, pos_y: i32, color: Color) {
+        unsafe {
+            ImageDrawPixel(&mut self.inner, pos_x as c_int, pos_y as c_int, color.to_ccolor());
+        }
+    }
+
+    pub fn draw_line(&mut self, start_pos_x: i32, start_pos_y: i32, end_pos_x: i32, end_pos_y: i32, color: Color) {
+        unsafe {
+            ImageDrawLine(&mut self.inner, start_pos_x as c_int, start_pos_y as c_int, end_pos_x as c_int, end_pos_y as c_int, color.to_ccolor());
+        }
+    }
+
+    pub fn draw_line_ex(&mut self, start: Vector2, end: Vector2, thickness: i32, color: Color) {
+        unsafe {
+            ImageDrawLineEx(&mut self.inner, start.to_cvector2(), end.to_cvector2(), thickness as c_int, color.to_ccolor());
+        }
+    }
+
+    pub fn draw_circle(&mut self, center_x: i32, center_y: i32, radius: i32, color: Color) {
+        unsafe {
+            ImageDrawCircle(&mut self.inner, center_x as c_int, center_y as c_int, radius as c_int, color.to_ccolor());
+        }
+    }
+
+    pub fn draw_circle_lines(&mut self, center_x: i32, center_y: i32, radius: i32, color: Color) {
+        unsafe {
+            ImageDrawCircleLines(&mut self.inner, center_x as c_int, center_y as c_int, radius as c_int, color.to_ccolor());
+        }
+    }
+
+    pub fn draw_rectangle(&mut self, pos_x: i32, pos_y: i32, width: i32, height: i32, color: Color) {
+        unsafe {
+            ImageDrawRectangle(&mut self.inner, pos_x as c_int, pos_y as c_int, width as c_int, height as c_int, color.to_ccolor());
+        }
+    }
+
+    pub fn draw_rectangle_rec(&mut self, rec: Rectangle, color: Color) {
+        unsafe {
+            ImageDrawRectangleRec(&mut self.inner, rec.to_crectangle(), color.to_ccolor());
+        }
+    }
+
+    pub fn draw_rectangle_lines(&mut self, rec: Rectangle, thickness: i32, color: Color) {
+        unsafe {
+            ImageDrawRectangleLines(&mut self.inner, rec.to_crectangle(), thickness as c_int, color.to_ccolor());
+        }
+    }
+
+    pub fn draw_triangle(&mut self, v1: Vector2, v2: Vector2, v3: Vector2, color: Color) {
+        unsafe {
+            ImageDrawTriangle(&mut self.inner, v1.to_cvector2(), v2.to_cvector2(), v3.to_cvector2(), color.to_ccolor());
+        }
+    }
+
+    // Draws src_rec of src into dst_rec of this image, scaling as needed.
+    pub fn draw_image(&mut self, src: &Image, src_rec: Rectangle, dst_rec: Rectangle, tint: Color) {
+        unsafe {
+            ImageDraw(&mut self.inner, src.to_cimage(), src_rec.to_crectangle(), dst_rec.to_crectangle(), tint.to_ccolor());
+        }
+    }
+
+    // Panics if text contains a NUL byte. See try_draw_text.
+    pub fn draw_text(&mut self, font: &DefaultFont, text: &str, pos_x: i32, pos_y: i32, font_size: i32, color: Color) {
+        self.try_draw_text(font, text, pos_x, pos_y, font_size, color).expect("Failed to create CString.");
+    }
+
+    // Takes the default font, since it's only loaded while a window is open. See WindowHandle::get_default_font.
+    // Spaces the glyphs font_size / 10 apart, as raylib's ImageDrawText does.
+    pub fn try_draw_text(&mut self, font: &DefaultFont, text: &str, pos_x: i32, pos_y: i32, font_size: i32, color: Color) -> Result<(), Error> {
+        let converted_text = CString::new(text)?;
+        let position = Vector2::new(pos_x as f32, pos_y as f32);
+
+        unsafe {
+            ImageDrawTextEx(&mut self.inner, font.to_cfont(), converted_text.as_ptr(), position.to_cvector2(), font_size as c_float, (font_size as f32 / 10.0) as c_float, color.to_ccolor());
+        }
+
+        return Ok(());
+    }
+
+    // Panics if text contains a NUL byte. See try_draw_text_ex.
+    pub fn draw_text_ex(&mut self, font: &Font, text: &str, position: Vector2, font_size: f32, spacing: f32, tint: Color) {
+        self.try_draw_text_ex(font, text, position, font_size, spacing, tint).expect("Failed to create CString.");
+    }
+
+    pub fn try_draw_text_ex(&mut self, font: &Font, text: &str, position: Vector2, font_size: f32, spacing: f32, tint: Color) -> Result<(), Error> {
+        let converted_text = CString::new(text)?;
+
+        unsafe {
+            ImageDrawTextEx(&mut self.inner, font.to_cfont(), converted_text.as_ptr(), position.to_cvector2(), font_size as c_float, spacing as c_float, tint.to_ccolor());
+        }
+
+        return Ok(());
+    }
+
+    // The format is picked from the extension: .png, .bmp, .tga, .jpg, .qoi, or .raw for the bare pixel data.
+    pub fn export(&self, file_name: &str) -> Result<(), Error> {
+        let converted_file_name = CString::new(file_name)?;
+        let success;
+
+        unsafe {
+            success = ExportImage(self.to_cimage(), converted_file_name.as_ptr());
+        }
+
+        if !success {
+            return Err(Error::ResourceExportFailed(file_name.to_string()));
+        }
+
+        return Ok(());
+    }
+
+    // file_type is the extension, including the dot, e.g. ".png".
+    pub fn export_to_memory(&self, file_type: &str) -> Result<Vec<u8>, Error> {
+        let converted_file_type = CString::new(file_type)?;
+        let mut file_size: c_int = 0;
+        let data;
+
+        unsafe {
+            data = ExportImageToMemory(self.to_cimage(), converted_file_type.as_ptr(), &mut file_size);
+        }
+
+        if data.is_null() {
+            return Err(Error::ResourceExportFailed(file_type.to_string()));
+        }
+
+        let result;
+
+        unsafe {
+            result = slice::from_raw_parts(data, file_size as usize).to_vec();
+            MemFree(data as *mut c_void);
+        }
+
+        return Ok(result);
+    }
+}
+
+impl Clone for Image {
+    fn clone(&self) -> Image {
+        let result;
+
+        unsafe {
+            result = ImageCopy(self.to_cimage());
+        }
+
+        return Image { inner: result };
+    }
+}
+
+impl Drop for Image {
+    fn drop(&mut self) {
+        unsafe {
+            UnloadImage(self.to_cimage());
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PixelFormat {
+    UncompressedGrayscale,      // 8 bit per pixel (no alpha)
+    UncompressedGrayAlpha,      // 8*2 bpp (2 channels)
+    UncompressedR5G6B5,         // 16 bpp
+    UncompressedR8G8B8,         // 24 bpp
+    UncompressedR5G5B5A1,       // 16 bpp (1 bit alpha)
+    UncompressedR4G4B4A4,       // 16 bpp (4 bit alpha)
+    UncompressedR8G8B8A8,       // 32 bpp
+    UncompressedR32,            // 32 bpp (1 channel - float)
+    UncompressedR32G32B32,      // 32*3 bpp (3 channels - float)
+    UncompressedR32G32B32A32,   // 32*4 bpp (4 channels - float)
+    UncompressedR16,            // 16 bpp (1 channel - half float)
+    UncompressedR16G16B16,      // 16*3 bpp (3 channels - half float)
+    UncompressedR16G16B16A16,   // 16*4 bpp (4 channels - half float)
+    CompressedDxt1Rgb,          // 4 bpp (no alpha)
+    CompressedDxt1Rgba,         // 4 bpp (1 bit alpha)
+    CompressedDxt3Rgba,         // 8 bpp
+    CompressedDxt5Rgba,         // 8 bpp
+    CompressedEtc1Rgb,          // 4 bpp
+    CompressedEtc2Rgb,          // 4 bpp
+    CompressedEtc2EacRgba,      // 8 bpp
+    CompressedPvrtRgb,          // 4 bpp
+    CompressedPvrtRgba,         // 4 bpp
+    CompressedAstc4x4Rgba,      // 8 bpp
+    CompressedAstc8x8Rgba,      // 2 bpp
+}
+
+impl PixelFormat {
+    fn to_format_code(&self) -> c_int {
+        match self {
+            PixelFormat::UncompressedGrayscale    => 1,
+            PixelFormat::UncompressedGrayAlpha    => 2,
+            PixelFormat::UncompressedR5G6B5       => 3,
+            PixelFormat::UncompressedR8G8B8       => 4,
+            PixelFormat::UncompressedR5G5B5A1     => 5,
+            PixelFormat::UncompressedR4G4B4A4     => 6,
+            PixelFormat::UncompressedR8G8B8A8     => 7,
+            PixelFormat::UncompressedR32          => 8,
+            PixelFormat::UncompressedR32G32B32    => 9,
+            PixelFormat::UncompressedR32G32B32A32 => 10,
+            PixelFormat::UncompressedR16          => 11,
+            PixelFormat::UncompressedR16G16B16    => 12,
+            PixelFormat::UncompressedR16G16B16A16 => 13,
+            PixelFormat::CompressedDxt1Rgb        => 14,
+            PixelFormat::CompressedDxt1Rgba       => 15,
+            PixelFormat::CompressedDxt3Rgba       => 16,
+            PixelFormat::CompressedDxt5Rgba       => 17,
+            PixelFormat::CompressedEtc1Rgb        => 18,
+            PixelFormat::CompressedEtc2Rgb        => 19,
+            PixelFormat::CompressedEtc2EacRgba    => 20,
+            PixelFormat::CompressedPvrtRgb        => 21,
+            PixelFormat::CompressedPvrtRgba       => 22,
+            PixelFormat::CompressedAstc4x4Rgba    => 23,
+            PixelFormat::CompressedAstc8x8Rgba    => 24,
+        }
+    }
+
+    // Raylib only ever hands back formats from the list above.
+    fn from_format_code(code: c_int) -> PixelFormat {
+        match code {
+            1  => PixelFormat::UncompressedGrayscale,
+            2  => PixelFormat::UncompressedGrayAlpha,
+            3  => PixelFormat::UncompressedR5G6B5,
+            4  => PixelFormat::UncompressedR8G8B8,
+            5  => PixelFormat::UncompressedR5G5B5A1,
+            6  => PixelFormat::UncompressedR4G4B4A4,
+            7  => PixelFormat::UncompressedR8G8B8A8,
+            8  => PixelFormat::UncompressedR32,
+            9  => PixelFormat::UncompressedR32G32B32,
+            10 => PixelFormat::UncompressedR32G32B32A32,
+            11 => PixelFormat::UncompressedR16,
+            12 => PixelFormat::UncompressedR16G16B16,
+            13 => PixelFormat::UncompressedR16G16B16A16,
+            14 => PixelFormat::CompressedDxt1Rgb,
+            15 => PixelFormat::CompressedDxt1Rgba,
+            16 => PixelFormat::CompressedDxt3Rgba,
+            17 => PixelFormat::CompressedDxt5Rgba,
+            18 => PixelFormat::CompressedEtc1Rgb,
+            19 => PixelFormat::CompressedEtc2Rgb,
+            20 => PixelFormat::CompressedEtc2EacRgba,
+            21 => PixelFormat::CompressedPvrtRgb,
+            22 => PixelFormat::CompressedPvrtRgba,
+            23 => PixelFormat::CompressedAstc4x4Rgba,
+            24 => PixelFormat::CompressedAstc8x8Rgba,
+            _  => panic!("Unknown pixel format {}.", code),
+        }
+    }
+}
+
+// Textures live in GPU memory, so they can only be loaded once a window exists, and can't outlive it.
+pub struct Texture2D<'w> {
+    inner: CTexture2D,
+    _window: PhantomData<&'w WindowHandle>,
+}
+
+impl<'w> Texture2D<'w> {
+    fn to_ctexture(&self) -> CTexture2D {
+        return self.inner.clone();
+    }
+
+    fn from_ctexture(texture: CTexture2D, source: &str) -> Result<Texture2D<'w>, Error> {
+        let valid;
+
+        unsafe {
+            valid = IsTextureValid(texture.clone());
+        }
+
+        if !valid {
+            return Err(Error::ResourceLoadFailed(source.to_string()));
+        }
+
+        return Ok(Texture2D { inner: texture, _window: PhantomData });
+    }
+
+    pub fn width(&self) -> i32 {
+        return self.inner.width;
+    }
+
+    pub fn height(&self) -> i32 {
+        return self.inner.height;
+    }
+}
+
+impl<'w> Drop for Texture2D<'w> {
+    fn drop(&mut self) {
+        unsafe {
+            UnloadTexture(self.to_ctexture());
+        }
+    }
+}
+
+impl WindowHandle {
+    pub fn load_texture(&self, file_name: &str) -> Result<Texture2D<'_>, Error> {
+        let converted_file_name = CString::new(file_name)?;
+        let result;
+
+        unsafe {
+            result = LoadTexture(converted_file_name.as_ptr());
+        }
+
+        return Texture2D::from_ctexture(result, file_name);
+    }
+
+    pub fn load_texture_from_image(&self, image: &Image) -> Result<Texture2D<'_>, Error> {
+        let result;
+
+        unsafe {
+            result = LoadTextureFromImage(image.to_cimage());
+        }
+
+        return Texture2D::from_ctexture(result, "image");
+    }
+}
+
+impl WindowContext {
+    // Grabs the current contents of the framebuffer.
+    pub fn screenshot(&self) -> Result<Image, Error> {
+        let result;
+
+        unsafe {
+            result = LoadImageFromScreen();
+        }
+
+        return Image::from_cimage(result, "screen");
+    }
+
+    // Saves the framebuffer straight to disk as a png.
+    pub fn take_screenshot(&self, file_name: &str) -> Result<(), Error> {
+        let converted_file_name = CString::new(file_name)?;
+
+        unsafe {
+            TakeScreenshot(converted_file_name.as_ptr());
+        }
+
+        return Ok(());
+    }
+}
+
+// Offscreen framebuffer. Its color attachment can be drawn like any other texture through texture().
+pub struct RenderTexture2D<'w> {
+    id: u32,
+    // Owned by the render texture, and unloaded along with it.
+    texture: ManuallyDrop<Texture2D<'w>>,
+    depth: CTexture,
+}
+
+impl<'w> RenderTexture2D<'w> {
+    fn to_crender_texture(&self) -> CRenderTexture2D {
+        return CRenderTexture {
+            id: self.id,
+            texture: self.texture.to_ctexture(),
+            depth: self.depth.clone(),
+        };
+    }
+
+    pub fn texture(&self) -> &Texture2D<'w> {
+        return &self.texture;
+    }
+
+    pub fn width(&self) -> i32 {
+        return self.texture.width();
+    }
+
+    pub fn height(&self) -> i32 {
+        return self.texture.height();
+    }
+}
+
+impl<'w> Drop for RenderTexture2D<'w> {
+    fn drop(&mut self) {
+        unsafe {
+            UnloadRenderTexture(self.to_crender_texture());
+        }
+    }
+}
+
+impl WindowHandle {
+    pub fn load_render_texture(&self, width: i32, height: i32) -> Result<RenderTexture2D<'_>, Error> {
+        if width < 0 || height < 0 {
+            return Err(Error::InvalidSize(width, height));
+        }
+
+        let result;
+        let valid;
+
+        unsafe {
+            result = LoadRenderTexture(width as c_int, height as c_int);
+            valid = IsRenderTextureValid(result.clone());
+        }
+
+        if !valid {
+            return Err(Error::ResourceLoadFailed("render texture".to_string()));
+        }
+
+        return Ok(RenderTexture2D {
+            id: result.id,
+            texture: ManuallyDrop::new(Texture2D { inner: result.texture, _window: PhantomData }),
+            depth: result.depth,
+        });
+    }
+}
+
+// Holds the render texture mutably so it can't be sampled while it is being drawn to.
+pub struct TextureModeContext<'a, 'w> {
+    drawing: &'a mut DrawingContext<'w>,
+    // The outer guard still borrows its render texture, so this copy can't dangle.
+    outer_target: Option<CRenderTexture2D>,
+    outer_camera: Option<ActiveCamera>,
+    _target: PhantomData<&'a mut ()>,
+}
+
+impl<'a, 'w> Deref for TextureModeContext<'a, 'w> {
+    type Target = DrawingContext<'w>;
+
+    fn deref(&self) -> &DrawingContext<'w> {
+        return self.drawing;
+    }
+}
+
+impl<'a, 'w> DerefMut for TextureModeContext<'a, 'w> {
+    fn deref_mut(&mut self) -> &mut DrawingContext<'w> {
+        return self.drawing;
+    }
+}
+
+impl<'a, 'w> Drop for TextureModeContext<'a, 'w> {
+    fn drop(&mut self) {
+        let outer_target = self.outer_target.take();
+
+        unsafe {
+            EndTextureMode();
+
+            if let Some(target) = outer_target.clone() {
+                BeginTextureMode(target);
+            }
+        }
+
+        if let Some(camera) = self.outer_camera {
+            camera.reapply();
+        }
+
+        self.drawing.render_target = outer_target;
+        self.drawing.camera = self.outer_camera;
+    }
+}
+
+/*===================================
+              Cameras.
+=====================================*/
+
+#[derive(Clone, Copy, Debug)]
+pub struct Camera2D {
+    pub offset: Vector2,    // Where target ends up on screen.
+    pub target: Vector2,    // The world position the camera looks at.
+    pub rotation: f32,      // In degrees.
+    pub zoom: f32,          // 1.0 for no scaling.
+}
+
+impl Camera2D {
+    fn to_ccamera2d(&self) -> CCamera2D {
+        return CCamera2D {
+            offset: self.offset.to_cvector2(),
+            target: self.target.to_cvector2(),
+            rotation: self.rotation as c_float,
+            zoom: self.zoom as c_float,
+        };
+    }
+}
+
+pub fn get_screen_to_world_2d(position: Vector2, camera: Camera2D) -> Vector2 {
+    let result;
+
+    unsafe {
+        result = GetScreenToWorld2D(position.to_cvector2(), camera.to_ccamera2d());
+    }
+
+    return Vector2 { x: result.x, y: result.y };
+}
+
+pub fn get_world_to_screen_2d(position: Vector2, camera: Camera2D) -> Vector2 {
+    let result;
+
+    unsafe {
+        result = GetWorldToScreen2D(position.to_cvector2(), camera.to_ccamera2d());
+    }
+
+    return Vector2 { x: result.x, y: result.y };
+}
+
+// The camera a mode guard has to put back when a nested mode ends.
+#[derive(Clone, Copy)]
+enum ActiveCamera {
+    Mode2D(Camera2D),
+    Mode3D(Camera3D),
+}
+
+impl ActiveCamera {
+    fn reapply(&self) {
+        unsafe {
+            match self {
+                ActiveCamera::Mode2D(camera) => {
+                    BeginMode2D(camera.to_ccamera2d());
+                },
+                // The 3D mode is still on raylib's projection stack, so pop it before pushing it again.
+                ActiveCamera::Mode3D(camera) => {
+                    EndMode3D();
+                    BeginMode3D(camera.to_ccamera3d());
+                },
+            }
+        }
+    }
+}
+
+pub struct Mode2DContext<'a, 'w> {
+    drawing: &'a mut DrawingContext<'w>,
+    outer_camera: Option<ActiveCamera>,
+}
+
+impl<'a, 'w> Deref for Mode2DContext<'a, 'w> {
+    type Target = DrawingContext<'w>;
+
+    fn deref(&self) -> &DrawingContext<'w> {
+        return self.drawing;
+    }
+}
+
+impl<'a, 'w> DerefMut for Mode2DContext<'a, 'w> {
+    fn deref_mut(&mut self) -> &mut DrawingContext<'w> {
+        return self.drawing;
+    }
+}
+
+impl<'a, 'w> Drop for Mode2DContext<'a, 'w> {
+    fn drop(&mut self) {
+        unsafe {
+            EndMode2D();
+        }
+
+        if let Some(camera) = self.outer_camera {
+            camera.reapply();
+        }
+
+        self.drawing.camera = self.outer_camera;
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CameraProjection {
+    Perspective,
+    Orthographic,
+}
+
+impl CameraProjection {
+    fn to_projection_code(&self) -> c_int {
+        match self {
+            CameraProjection::Perspective  => 0,
+            CameraProjection::Orthographic => 1,
+        }
+    }
+
+    fn from_projection_code(code: c_int) -> CameraProjection {
+        match code {
+            1 => CameraProjection::Orthographic,
+            _ => CameraProjection::Perspective,
+        }
+    }
+}
+
+// How WindowContext::update_camera moves the camera from user input.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CameraMode {
+    Custom,         // Doesn't move the camera.
+    Free,
+    Orbital,        // Orbits around target, with zoom.
+    FirstPerson,
+    ThirdPerson,
+}
+
+impl CameraMode {
+    fn to_mode_code(&self) -> c_int {
+        match self {
+            CameraMode::Custom      => 0,
+            CameraMode::Free        => 1,
+            CameraMode::Orbital     => 2,
+            CameraMode::FirstPerson => 3,
+            CameraMode::ThirdPerson => 4,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Camera3D {
+    pub position: Vector3,
+    pub target: Vector3,    // The point the camera looks at.
+    pub up: Vector3,        // Usually Vector3::UP.
+    pub fovy: f32,          // Field of view in degrees when perspective, the near plane width when orthographic.
+    pub projection: CameraProjection,
+}
+
+impl Camera3D {
+    fn to_ccamera3d(&self) -> CCamera3D {
+        return CCamera3D {
+            position: self.position,
+            target: self.target,
+            up: self.up,
+            fovy: self.fovy as c_float,
+            projection: self.projection.to_projection_code(),
+        };
+    }
+
+    fn from_ccamera3d(camera: CCamera3D) -> Camera3D {
+        return Camera3D {
+            position: camera.position,
+            target: camera.target,
+            up: camera.up,
+            fovy: camera.fovy,
+            projection: CameraProjection::from_projection_code(camera.projection),
+        };
+    }
+
+    pub fn perspective(position: Vector3, target: Vector3, up: Vector3, fovy: f32) -> Camera3D {
+        return Camera3D { position, target, up, fovy, projection: CameraProjection::Perspective };
+    }
+
+    pub fn orthographic(position: Vector3, target: Vector3, up: Vector3, fovy: f32) -> Camera3D {
+        return Camera3D { position, target, up, fovy, projection: CameraProjection::Orthographic };
+    }
+
+    pub fn view_matrix(&self) -> Matrix {
+        return Matrix::look_at(self.position, self.target, self.up);
+    }
+}
+
+impl WindowContext {
+    // Reads this frame's mouse and keyboard input, so call it once per frame.
+    pub fn update_camera(&self, camera: &mut Camera3D, mode: CameraMode) {
+        let mut converted_camera = camera.to_ccamera3d();
+
+        unsafe {
+            UpdateCamera(&mut converted_camera, mode.to_mode_code());
+        }
+
+        *camera = Camera3D::from_ccamera3d(converted_camera);
+    }
+
+    // The ray from the camera through position on the screen, e.g. get_mouse_position() for picking.
+    pub fn get_screen_to_world_ray(&self, position: Vector2, camera: &Camera3D) -> Ray {
+        let result;
+
+        unsafe {
+            result = GetScreenToWorldRay(position.to_cvector2(), camera.to_ccamera3d());
+        }
+
+        return result;
+    }
+
+    pub fn get_world_to_screen(&self, position: Vector3, camera: &Camera3D) -> Vector2 {
+        let result;
+
+        unsafe {
+            result = GetWorldToScreen(position, camera.to_ccamera3d());
+        }
+
+        return Vector2 { x: result.x, y: result.y };
+    }
+}
+
+/*===================================
+            3D drawing.
+=====================================*/
+
+// 3D shapes can only be drawn inside 3D mode, so they hang off this context rather than DrawingContext.
+pub struct Mode3DContext<'a, 'w> {
+    drawing: &'a mut DrawingContext<'w>,
+    camera: Camera3D,
+    outer_camera: Option<ActiveCamera>,
+}
+
+impl<'a, 'w> Deref for Mode3DContext<'a, 'w> {
+    type Target = DrawingContext<'w>;
+
+    fn deref(&self) -> &DrawingContext<'w> {
+        return self.drawing;
+    }
+}
+
+impl<'a, 'w> DerefMut for Mode3DContext<'a, 'w> {
+    fn deref_mut(&mut self) -> &mut DrawingContext<'w> {
+        return self.drawing;
+    }
+}
+
+impl<'a, 'w> Drop for Mode3DContext<'a, 'w> {
+    fn drop(&mut self) {
+        unsafe {
+            EndMode3D();
+        }
+
+        if let Some(camera) = self.outer_camera {
+            camera.reapply();
+        }
+
+        self.drawing.camera = self.outer_camera;
+    }
+}
+
+impl<'a, 'w> Mode3DContext<'a, 'w> {
+    pub fn camera(&self) -> Camera3D {
+        return self.camera;
+    }
+
+    pub fn draw_line_3d(&mut self, start_pos: Vector3, end_pos: Vector3, color: Color) {
+        unsafe {
+            DrawLine3D(start_pos, end_pos, color.to_ccolor());
+        }
+    }
+
+    pub fn draw_point_3d(&mut self, position: Vector3, color: Color) {
+        unsafe {
+            DrawPoint3D(position, color.to_ccolor());
+        }
+    }
+
+    // Drawn on the XY plane, then rotated by rotation_angle degrees around rotation_axis.
+    pub fn draw_circle_3d(&mut self, center: Vector3, radius: f32, rotation_axis: Vector3, rotation_angle: f32, color: Color) {
+        unsafe {
+            DrawCircle3D(center, radius as c_float, rotation_axis, rotation_angle as c_float, color.to_ccolor());
+        }
+    }
+
+    // Vertices must be in counter-clockwise order, or only the back face is drawn.
+    pub fn draw_triangle_3d(&mut self, v1: Vector3, v2: Vector3, v3: Vector3, color: Color) {
+        unsafe {
+            DrawTriangle3D(v1, v2, v3, color.to_ccolor());
+        }
+    }
+
+    pub fn draw_cube(&mut self, position: Vector3, width: f32, height: f32, length: f32, color: Color) {
+        unsafe {
+            DrawCube(position, width as c_float, height as c_float, length as c_float, color.to_ccolor());
+        }
+    }
+
+    pub fn draw_cube_v(&mut self, position: Vector3, size: Vector3, color: Color) {
+        unsafe {
+            DrawCubeV(position, size, color.to_ccolor());
+        }
+    }
+
+    pub fn draw_cube_wires(&mut self, position: Vector3, width: f32, height: f32, length: f32, color: Color) {
+        unsafe {
+            DrawCubeWires(position, width as c_float, height as c_float, length as c_float, color.to_ccolor());
+        }
+    }
+
+    pub fn draw_cube_wires_v(&mut self, position: Vector3, size: Vector3, color: Color) {
+        unsafe {
+            DrawCubeWiresV(position, size, color.to_ccolor());
+        }
+    }
+
+    pub fn draw_sphere(&mut self, center_pos: Vector3, radius: f32, color: Color) {
+        unsafe {
+            DrawSphere(center_pos, radius as c_float, color.to_ccolor());
+        }
+    }
+
+    pub fn draw_sphere_ex(&mut self, center_pos: Vector3, radius: f32, rings: i32, slices: i32, color: Color) {
+        unsafe {
+            DrawSphereEx(center_pos, radius as c_float, rings as c_int, slices as c_int, color.to_ccolor());
+        }
+    }
+
+    pub fn draw_sphere_wires(&mut self, center_pos: Vector3, radius: f32, rings: i32, slices: i32, color: Color) {
+        unsafe {
+            DrawSphereWires(center_pos, radius as c_float, rings as c_int, slices as c_int, color.to_ccolor());
+        }
+    }
+
+    // position is the center of the bottom face.
+    pub fn draw_cylinder(&mut self, position: Vector3, radius_top: f32, radius_bottom: f32, height: f32, slices: i32, color: Color) {
+        unsafe {
+            DrawCylinder(position, radius_top as c_float, radius_bottom as c_float, height as c_float, slices as c_int, color.to_ccolor());
+        }
+    }
+
+    pub fn draw_cylinder_ex(&mut self, start_pos: Vector3, end_pos: Vector3, start_radius: f32, end_radius: f32, sides: i32, color: Color) {
+        unsafe {
+            DrawCylinderEx(start_pos, end_pos, start_radius as c_float, end_radius as c_float, sides as c_int, color.to_ccolor());
+        }
+    }
+
+    pub fn draw_cylinder_wires(&mut self, position: Vector3, radius_top: f32, radius_bottom: f32, height: f32, slices: i32, color: Color) {
+        unsafe {
+            DrawCylinderWires(position, radius_top as c_float, radius_bottom as c_float, height as c_float, slices as c_int, color.to_ccolor());
+        }
+    }
+
+    pub fn draw_cylinder_wires_ex(&mut self, start_pos: Vector3, end_pos: Vector3, start_radius: f32, end_radius: f32, sides: i32, color: Color) {
+        unsafe {
+            DrawCylinderWiresEx(start_pos, end_pos, start_radius as c_float, end_radius as c_float, sides as c_int, color.to_ccolor());
+        }
+    }
+
+    pub fn draw_capsule(&mut self, start_pos: Vector3, end_pos: Vector3, radius: f32, slices: i32, rings: i32, color: Color) {
+        unsafe {
+            DrawCapsule(start_pos, end_pos, radius as c_float, slices as c_int, rings as c_int, color.to_ccolor());
+        }
+    }
+
+    pub fn draw_capsule_wires(&mut self, start_pos: Vector3, end_pos: Vector3, radius: f32, slices: i32, rings: i32, color: Color) {
+        unsafe {
+            DrawCapsuleWires(start_pos, end_pos, radius as c_float, slices as c_int, rings as c_int, color.to_ccolor());
+        }
+    }
+
+    // On the XZ plane.
+    pub fn draw_plane(&mut self, center_pos: Vector3, size: Vector2, color: Color) {
+        unsafe {
+            DrawPlane(center_pos, size.to_cvector2(), color.to_ccolor());
+        }
+    }
+
+    pub fn draw_ray(&mut self, ray: Ray, color: Color) {
+        unsafe {
+            DrawRay(ray, color.to_ccolor());
+        }
+    }
+
+    // Centered on the origin, on the XZ plane.
+    pub fn draw_grid(&mut self, slices: i32, spacing: f32) {
+        unsafe {
+            DrawGrid(slices as c_int, spacing as c_float);
+        }
+    }
+
+    pub fn draw_bounding_box(&mut self, bbox: BoundingBox, color: Color) {
+        unsafe {
+            DrawBoundingBox(bbox, color.to_ccolor());
+        }
+    }
+
+    pub fn draw_model(&mut self, model: &Model, position: Vector3, scale: f32, tint: Color) {
+        unsafe {
+            DrawModel(model.to_cmodel(), position, scale as c_float, tint.to_ccolor());
+        }
+    }
+
+    // Rotation is rotation_angle degrees around rotation_axis.
+    pub fn draw_model_ex(&mut self, model: &Model, position: Vector3, rotation_axis: Vector3, rotation_angle: f32, scale: Vector3, tint: Color) {
+        unsafe {
+            DrawModelEx(model.to_cmodel(), position, rotation_axis, rotation_angle as c_float, scale, tint.to_ccolor());
+        }
+    }
+
+    pub fn draw_model_wires(&mut self, model: &Model, position: Vector3, scale: f32, tint: Color) {
+        unsafe {
+            DrawModelWires(model.to_cmodel(), position, scale as c_float, tint.to_ccolor());
+        }
+    }
+
+    pub fn draw_model_wires_ex(&mut self, model: &Model, position: Vector3, rotation_axis: Vector3, rotation_angle: f32, scale: Vector3, tint: Color) {
+        unsafe {
+            DrawModelWiresEx(model.to_cmodel(), position, rotation_axis, rotation_angle as c_float, scale, tint.to_ccolor());
+        }
+    }
+
+    pub fn draw_mesh(&mut self, mesh: &Mesh, material: &Material, transform: Matrix) {
+        unsafe {
+            DrawMesh(mesh.to_cmesh(), material.to_cmaterial(), transform);
+        }
+    }
+
+    // Billboards always face the camera this mode was started with.
+    pub fn draw_billboard(&mut self, texture: &Texture2D, position: Vector3, scale: f32, tint: Color) {
+        unsafe {
+            DrawBillboard(self.camera.to_ccamera3d(), texture.to_ctexture(), position, scale as c_float, tint.to_ccolor());
+        }
+    }
+
+    pub fn draw_billboard_rec(&mut self, texture: &Texture2D, source: Rectangle, position: Vector3, size: Vector2, tint: Color) {
+        unsafe {
+            DrawBillboardRec(self.camera.to_ccamera3d(), texture.to_ctexture(), source.to_crectangle(), position, size.to_cvector2(), tint.to_ccolor());
+        }
+    }
+
+    // Rotation is in degrees, around origin. Takes the same arguments as raylib's DrawBillboardPro.
+    #[allow(clippy::too_many_arguments)]
+    pub fn draw_billboard_pro(&mut self, texture: &Texture2D, source: Rectangle, position: Vector3, up: Vector3, size: Vector2, origin: Vector2, rotation: f32, tint: Color) {
+        unsafe {
+            DrawBillboardPro(self.camera.to_ccamera3d(), texture.to_ctexture(), source.to_crectangle(), position, up, size.to_cvector2(), origin.to_cvector2(), rotation as c_float, tint.to_ccolor());
+        }
+    }
+}
+
+/*===================================
+              Splines.
+=====================================*/
+
+// Evaluation is done in Rust, with the same formulas raylib draws with, so sampling and drawing agree.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SplineKind {
+    Linear,             // At least 2 points. Each consecutive pair is a segment.
+    Basis,              // At least 4 points. Each window of 4 is a segment. Doesn't pass through the points.
+    CatmullRom,         // At least 4 points. Each window of 4 is a segment, running from the 2nd to the 3rd point.
+    BezierQuadratic,    // 3 points, then 2 more per extra segment: [p1, c2, p3, c4, p5, ...].
+    BezierCubic,        // 4 points, then 3 more per extra segment: [p1, c2, c3, p4, c5, c6, p7, ...].
+}
+
+impl SplineKind {
+    // Points in one segment, and how far the window moves between segments.
+    fn window(&self) -> (usize, usize) {
+        match self {
+            SplineKind::Linear          => (2, 1),
+            SplineKind::Basis           => (4, 1),
+            SplineKind::CatmullRom      => (4, 1),
+            SplineKind::BezierQuadratic => (3, 2),
+            SplineKind::BezierCubic     => (4, 3),
+        }
+    }
+
+    pub fn segment_count(&self, point_count: usize) -> usize {
+        let (size, step) = self.window();
+
+        if point_count < size {
+            return 0;
+        }
+
+        return (point_count - size) / step + 1;
+    }
+}
+
+// Maps t in [0.0, 1.0] across the whole spline to a segment's points and a t local to that segment.
+fn spline_segment(kind: SplineKind, points: &[Vector2], t: f32) -> Option<(&[Vector2], f32)> {
+    let segment_count = kind.segment_count(points.len());
+
+    if segment_count == 0 {
+        return None;
+    }
+
+    let (size, step) = kind.window();
+    let scaled = t.clamp(0.0, 1.0) * segment_count as f32;
+    let index = usize::min(scaled as usize, segment_count - 1);
+    let start = index * step;
+
+    return Some((&points[start..start + size], scaled - index as f32));
+}
+
+// Returns None if there aren't enough points for a single segment.
+pub fn spline_point(kind: SplineKind, points: &[Vector2], t: f32) -> Option<Vector2> {
+    let (p, t) = spline_segment(kind, points, t)?;
+
+    let result = match kind {
+        SplineKind::Linear => get_spline_point_linear(p[0], p[1], t),
+        SplineKind::Basis => get_spline_point_basis(p[0], p[1], p[2], p[3], t),
+        SplineKind::CatmullRom => get_spline_point_catmull_rom(p[0], p[1], p[2], p[3], t),
+        SplineKind::BezierQuadratic => get_spline_point_bezier_quad(p[0], p[1], p[2], t),
+        SplineKind::BezierCubic => get_spline_point_bezier_cubic(p[0], p[1], p[2], p[3], t),
+    };
+
+    return Some(result);
+}
+
+// The derivative with respect to the segment's local t; not normalized.
+pub fn spline_tangent(kind: SplineKind, points: &[Vector2], t: f32) -> Option<Vector2> {
+    let (p, t) = spline_segment(kind, points, t)?;
+
+    let result = match kind {
+        SplineKind::Linear => get_spline_tangent_linear(p[0], p[1], t),
+        SplineKind::Basis => get_spline_tangent_basis(p[0], p[1], p[2], p[3], t),
+        SplineKind::CatmullRom => get_spline_tangent_catmull_rom(p[0], p[1], p[2], p[3], t),
+        SplineKind::BezierQuadratic => get_spline_tangent_bezier_quad(p[0], p[1], p[2], t),
+        SplineKind::BezierCubic => get_spline_tangent_bezier_cubic(p[0], p[1], p[2], p[3], t),
+    };
+
+    return Some(result);
+}
+
+pub fn get_spline_point_linear(start_pos: Vector2, end_pos: Vector2, t: f32) -> Vector2 {
+    return start_pos * (1.0 - t) + end_pos * t;
+}
+
+pub fn get_spline_tangent_linear(start_pos: Vector2, end_pos: Vector2, _t: f32) -> Vector2 {
+    return end_pos - start_pos;
+}
+
+pub fn get_spline_point_basis(p1: Vector2, p2: Vector2, p3: Vector2, p4: Vector2, t: f32) -> Vector2 {
+    let a0 = (p1 * -1.0 + p2 * 3.0 - p3 * 3.0 + p4) / 6.0;
+    let a1 = (p1 * 3.0 - p2 * 6.0 + p3 * 3.0) / 6.0;
+    let a2 = (p1 * -3.0 + p3 * 3.0) / 6.0;
+    let a3 = (p1 + p2 * 4.0 + p3) / 6.0;
+
+    return a3 + (a2 + (a1 + a0 * t) * t) * t;
+}
+
+pub fn get_spline_tangent_basis(p1: Vector2, p2: Vector2, p3: Vector2, p4: Vector2, t: f32) -> Vector2 {
+    let a0 = (p1 * -1.0 + p2 * 3.0 - p3 * 3.0 + p4) / 6.0;
+    let a1 = (p1 * 3.0 - p2 * 6.0 + p3 * 3.0) / 6.0;
+    let a2 = (p1 * -3.0 + p3 * 3.0) / 6.0;
+
+    return a2 + a1 * (2.0 * t) + a0 * (3.0 * t * t);
+}
+
+pub fn get_spline_point_catmull_rom(p1: Vector2, p2: Vector2, p3: Vector2, p4: Vector2, t: f32) -> Vector2 {
+    let t2 = t * t;
+    let t3 = t2 * t;
+
+    let q0 = -t3 + 2.0 * t2 - t;
+    let q1 = 3.0 * t3 - 5.0 * t2 + 2.0;
+    let q2 = -3.0 * t3 + 4.0 * t2 + t;
+    let q3 = t3 - t2;
+
+    return (p1 * q0 + p2 * q1 + p3 * q2 + p4 * q3) * 0.5;
+}
+
+pub fn get_spline_tangent_catmull_rom(p1: Vector2, p2: Vector2, p3: Vector2, p4: Vector2, t: f32) -> Vector2 {
+    let t2 = t * t;
+
+    let q0 = -3.0 * t2 + 4.0 * t - 1.0;
+    let q1 = 9.0 * t2 - 10.0 * t;
+    let q2 = -9.0 * t2 + 8.0 * t + 1.0;
+    let q3 = 3.0 * t2 - 2.0 * t;
+
+    return (p1 * q0 + p2 * q1 + p3 * q2 + p4 * q3) * 0.5;
+}
+
+pub fn get_spline_point_bezier_quad(start_pos: Vector2, control_pos: Vector2, end_pos: Vector2, t: f32) -> Vector2 {
+    let a = (1.0 - t) * (1.0 - t);
+    let b = 2.0 * (1.0 - t) * t;
+    let c = t * t;
+
+    return start_pos * a + control_pos * b + end_pos * c;
+}
+
+pub fn get_spline_tangent_bezier_quad(start_pos: Vector2, control_pos: Vector2, end_pos: Vector2, t: f32) -> Vector2 {
+    return (control_pos - start_pos) * (2.0 * (1.0 - t)) + (end_pos - control_pos) * (2.0 * t);
+}
+
+pub fn get_spline_point_bezier_cubic(start_pos: Vector2, start_control_pos: Vector2, end_control_pos: Vector2, end_pos: Vector2, t: f32) -> Vector2 {
+    let a = (1.0 - t) * (1.0 - t) * (1.0 - t);
+    let b = 3.0 * (1.0 - t) * (1.0 - t) * t;
+    let c = 3.0 * (1.0 - t) * t * t;
+    let d = t * t * t;
+
+    return start_pos * a + start_control_pos * b + end_control_pos * c + end_pos * d;
+}
+
+pub fn get_spline_tangent_bezier_cubic(start_pos: Vector2, start_control_pos: Vector2, end_control_pos: Vector2, end_pos: Vector2, t: f32) -> Vector2 {
+    let a = 3.0 * (1.0 - t) * (1.0 - t);
+    let b = 6.0 * (1.0 - t) * t;
+    let c = 3.0 * t * t;
+
+    return (start_control_pos - start_pos) * a + (end_control_pos - start_control_pos) * b + (end_pos - end_control_pos) * c;
+}
+
+/*===================================
+            2D collisions.
+=====================================*/
+
+pub fn check_collision_recs(rec1: Rectangle, rec2: Rectangle) -> bool {
+    return rec1.intersects(rec2);
+}
+
+pub fn check_collision_circles(center1: Vector2, radius1: f32, center2: Vector2, radius2: f32) -> bool {
+    let result;
+
+    unsafe {
+        result = CheckCollisionCircles(center1.to_cvector2(), radius1 as c_float, center2.to_cvector2(), radius2 as c_float);
+    }
+
+    return result;
+}
+
+pub fn check_collision_circle_rec(center: Vector2, radius: f32, rec: Rectangle) -> bool {
+    let result;
+
+    unsafe {
+        result = CheckCollisionCircleRec(center.to_cvector2(), radius as c_float, rec.to_crectangle());
+    }
+
+    return result;
+}
+
+pub fn check_collision_circle_line(center: Vector2, radius: f32, p1: Vector2, p2: Vector2) -> bool {
+    let result;
+
+    unsafe {
+        result = CheckCollisionCircleLine(center.to_cvector2(), radius as c_float, p1.to_cvector2(), p2.to_cvector2());
+    }
+
+    return result;
+}
+
+pub fn check_collision_point_rec(point: Vector2, rec: Rectangle) -> bool {
+    return rec.contains_point(point);
+}
+
+pub fn check_collision_point_circle(point: Vector2, center: Vector2, radius: f32) -> bool {
+    let result;
+
+    unsafe {
+        result = CheckCollisionPointCircle(point.to_cvector2(), center.to_cvector2(), radius as c_float);
+    }
+
+    return result;
+}
+
+pub fn check_collision_point_triangle(point: Vector2, p1: Vector2, p2: Vector2, p3: Vector2) -> bool {
+    let result;
+
+    unsafe {
+        result = CheckCollisionPointTriangle(point.to_cvector2(), p1.to_cvector2(), p2.to_cvector2(), p3.to_cvector2());
+    }
+
+    return result;
+}
+
+// Threshold is how far, in pixels, the point may be from the line.
+pub fn check_collision_point_line(point: Vector2, p1: Vector2, p2: Vector2, threshold: i32) -> bool {
+    let result;
+
+    unsafe {
+        result = CheckCollisionPointLine(point.to_cvector2(), p1.to_cvector2(), p2.to_cvector2(), threshold as c_int);
+    }
+
+    return result;
+}
+
+// The polygon is closed automatically; don't repeat the first point at the end.
+pub fn check_collision_point_poly(point: Vector2, points: &[Vector2]) -> bool {
+    let converted_points: Vec<CVector2> = points.iter().map(|p| p.to_cvector2()).collect();
+    let result;
+
+    unsafe {
+        result = CheckCollisionPointPoly(point.to_cvector2(), converted_points.as_ptr(), converted_points.len() as c_int);
+    }
+
+    return result;
+}
+
+// Returns where the two line segments cross, if they do.
+pub fn check_collision_lines(start_pos1: Vector2, end_pos1: Vector2, start_pos2: Vector2, end_pos2: Vector2) -> Option<Vector2> {
+    let mut collision_point = CVector2 { x: 0.0, y: 0.0 };
+    let result;
+
+    unsafe {
+        result = CheckCollisionLines(start_pos1.to_cvector2(), end_pos1.to_cvector2(), start_pos2.to_cvector2(), end_pos2.to_cvector2(), &mut collision_point);
+    }
+
+    if !result {
+        return None;
+    }
+
+    return Some(Vector2 { x: collision_point.x, y: collision_point.y });
+}
+
+/*===================================
+              Models.
+=====================================*/
+
+// Raylib hands out arrays it allocated; each element is moved into its own Rust owner and only the array itself is freed.
+unsafe fn take_raylib_array<C, T>(array: *mut C, count: c_int, wrap: fn(C) -> T) -> Vec<T> {
+    let mut result = Vec::with_capacity(count as usize);
+
+    for i in 0..count as usize {
+        result.push(wrap(std::ptr::read(array.add(i))));
+    }
+
+    MemFree(array as *mut c_void);
+
+    return result;
+}
+
+unsafe fn raylib_slice<'a, T>(data: *const T, len: usize) -> &'a [T] {
+    if data.is_null() || len == 0 {
+        return &[];
+    }
+
+    return slice::from_raw_parts(data, len);
+}
+
+fn raylib_name(name: &[c_char; 32]) -> String {
+    let bytes: Vec<u8> = name.iter().take_while(|c| **c != 0).map(|c| *c as u8).collect();
+
+    return String::from_utf8_lossy(&bytes).into_owned();
+}
+
+// Vertex data stays readable from Rust after being uploaded to the GPU.
+#[repr(transparent)]
+pub struct Mesh<'w> {
+    inner: CMesh,
+    _window: PhantomData<&'w WindowHandle>,
+}
+
+impl<'w> Mesh<'w> {
+    fn to_cmesh(&self) -> CMesh {
+        return self.inner.clone();
+    }
+
+    pub fn vertex_count(&self) -> i32 {
+        return self.inner.vertex_count;
+    }
+
+    pub fn triangle_count(&self) -> i32 {
+        return self.inner.triangle_count;
+    }
+
+    pub fn vertices(&self) -> &[Vector3] {
+        unsafe {
+            return raylib_slice(self.inner.vertices as *const Vector3, self.inner.vertex_count as usize);
+        }
+    }
+
+    // The rest are optional, and come back empty when the mesh doesn't have them.
+    pub fn normals(&self) -> &[Vector3] {
+        unsafe {
+            return raylib_slice(self.inner.normals as *const Vector3, self.inner.vertex_count as usize);
+        }
+    }
+
+    pub fn texcoords(&self) -> &[Vector2] {
+        unsafe {
+            return raylib_slice(self.inner.texcoords as *const Vector2, self.inner.vertex_count as usize);
+        }
+    }
+
+    pub fn texcoords2(&self) -> &[Vector2] {
+        unsafe {
+            return raylib_slice(self.inner.texcoords2 as *const Vector2, self.inner.vertex_count as usize);
+        }
+    }
+
+    pub fn tangents(&self) -> &[Vector4] {
+        unsafe {
+            return raylib_slice(self.inner.tangents as *const Vector4, self.inner.vertex_count as usize);
+        }
+    }
+
+    pub fn colors(&self) -> &[Color] {
+        unsafe {
+            return raylib_slice(self.inner.colors as *const Color, self.inner.vertex_count as usize);
+        }
+    }
+
+    // Three per triangle. Empty for unindexed meshes, where every three vertices make a triangle.
+    pub fn indices(&self) -> &[u16] {
+        unsafe {
+            return raylib_slice(self.inner.indices as *const u16, (self.inner.triangle_count * 3) as usize);
+        }
+    }
+
+    pub fn bounding_box(&self) -> BoundingBox {
+        let result;
+
+        unsafe {
+            result = GetMeshBoundingBox(self.to_cmesh());
+        }
+
+        return result;
+    }
+
+    // Needs normals and texcoords.
+    pub fn gen_tangents(&mut self) {
+        unsafe {
+            GenMeshTangents(&mut self.inner);
+        }
+    }
+
+    // Exports as .obj, or as C code with a .h extension.
+    pub fn export(&self, file_name: &str) -> Result<(), Error> {
+        let converted_file_name = CString::new(file_name)?;
+        let success;
+
+        unsafe {
+            success = ExportMesh(self.to_cmesh(), converted_file_name.as_ptr());
+        }
+
+        if !success {
+            return Err(Error::ResourceExportFailed(file_name.to_string()));
+        }
+
+        return Ok(());
+    }
+}
+
+impl Drop for Mesh<'_> {
+    fn drop(&mut self) {
+        unsafe {
+            UnloadMesh(self.to_cmesh());
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MaterialMapIndex {
+    Albedo,         // Also known as diffuse.
+    Metalness,      // Also known as specular.
+    Normal,
+    Roughness,
+    Occlusion,
+    Emission,
+    Height,
+    Cubemap,
+    Irradiance,
+    Prefilter,
+    Brdf,
+}
+
+// Every material has this many maps, though only the ones in MaterialMapIndex are used.
+const MAX_MATERIAL_MAPS: usize = 12;
+
+impl MaterialMapIndex {
+    fn to_index(&self) -> usize {
+        match self {
+            MaterialMapIndex::Albedo     => 0,
+            MaterialMapIndex::Metalness  => 1,
+            MaterialMapIndex::Normal     => 2,
+            MaterialMapIndex::Roughness  => 3,
+            MaterialMapIndex::Occlusion  => 4,
+            MaterialMapIndex::Emission   => 5,
+            MaterialMapIndex::Height     => 6,
+            MaterialMapIndex::Cubemap    => 7,
+            MaterialMapIndex::Irradiance => 8,
+            MaterialMapIndex::Prefilter  => 9,
+            MaterialMapIndex::Brdf       => 10,
+        }
+    }
+}
+
+// Owns the textures in its maps, so they are unloaded along with it.
+#[repr(transparent)]
+pub struct Material<'w> {
+    inner: CMaterial,
+    _window: PhantomData<&'w WindowHandle>,
+}
+
+impl<'w> Material<'w> {
+    fn to_cmaterial(&self) -> CMaterial {
+        return self.inner.clone();
+    }
+
+    fn map(&mut self, map: MaterialMapIndex) -> &mut CMaterialMap {
+        unsafe {
+            return &mut *self.inner.maps.add(map.to_index());
+        }
+    }
+
+    fn maps(&self) -> &[CMaterialMap] {
+        unsafe {
+            return raylib_slice(self.inner.maps as *const CMaterialMap, MAX_MATERIAL_MAPS);
+        }
+    }
+
+    fn uses_texture(&self, id: c_uint) -> bool {
+        return self.maps().iter().any(|map| map.texture.id == id);
+    }
+
+    // Hands back the texture that was in the map, for the caller to unload if nothing else uses it.
+    fn replace_texture(&mut self, map: MaterialMapIndex, texture: Texture2D<'w>) -> CTexture2D {
+        let texture = ManuallyDrop::new(texture);
+
+        return mem::replace(&mut self.map(map).texture, texture.to_ctexture());
+    }
+
+    // Hands back the shader the material had, for the caller to unload if nothing else uses it.
+    fn replace_shader(&mut self, shader: Shader<'w>) -> CShader {
+        let shader = ManuallyDrop::new(shader);
+        shader.locations.take();
+
+        return mem::replace(&mut self.inner.shader, shader.to_cshader());
+    }
+
+    // Unloads whatever texture was in the map before, unless it was raylib's default or another map still uses it.
+    pub fn set_texture(&mut self, map: MaterialMapIndex, texture: Texture2D<'w>) {
+        let old_texture = self.replace_texture(map, texture);
+
+        if !self.uses_texture(old_texture.id) {
+            unload_material_texture(old_texture);
+        }
+    }
+
+    // Unloads the shader the material had before, unless it was raylib's default.
+    pub fn set_shader(&mut self, shader: Shader<'w>) {
+        let old_shader = self.replace_shader(shader);
+
+        if old_shader.id != self.inner.shader.id {
+            unload_material_shader(old_shader);
+        }
+    }
+
+    pub fn set_color(&mut self, map: MaterialMapIndex, color: Color) {
+        self.map(map).color = color.to_ccolor();
+    }
+
+    pub fn set_value(&mut self, map: MaterialMapIndex, value: f32) {
+        self.map(map).value = value as c_float;
+    }
+}
+
+impl Drop for Material<'_> {
+    fn drop(&mut self) {
+        unsafe {
+            UnloadMaterial(self.to_cmaterial());
+        }
+    }
+}
+
+// Materials fall back on raylib's default texture and shader, which must never be unloaded.
+fn unload_material_texture(texture: CTexture2D) {
+    unsafe {
+        if texture.id != 0 && texture.id != rlGetTextureIdDefault() {
+            UnloadTexture(texture);
+        }
+    }
+}
+
+fn unload_material_shader(shader: CShader) {
+    unsafe {
+        if shader.id != rlGetShaderIdDefault() {
+            UnloadShader(shader);
+        }
+    }
+}
+
+// Owns its meshes, and the textures and shaders its materials use.
+pub struct Model<'w> {
+    inner: CModel,
+    _window: PhantomData<&'w WindowHandle>,
+}
+
+impl<'w> Model<'w> {
+    fn to_cmodel(&self) -> CModel {
+        return self.inner.clone();
+    }
+
+    fn from_cmodel(model: CModel, source: &str) -> Result<Model<'w>, Error> {
+        let valid;
+
+        unsafe {
+            valid = IsModelValid(model.clone());
+        }
+
+        if !valid {
+            return Err(Error::ResourceLoadFailed(source.to_string()));
+        }
+
+        return Ok(Model { inner: model, _window: PhantomData });
+    }
+
+    pub fn transform(&self) -> Matrix {
+        return self.inner.transform;
+    }
+
+    pub fn set_transform(&mut self, transform: Matrix) {
+        self.inner.transform = transform;
+    }
+
+    pub fn meshes(&self) -> &[Mesh<'w>] {
+        unsafe {
+            return raylib_slice(self.inner.meshes as *const Mesh, self.inner.mesh_count as usize);
+        }
+    }
+
+    pub fn materials(&self) -> &[Material<'w>] {
+        unsafe {
+            return raylib_slice(self.inner.materials as *const Material, self.inner.material_count as usize);
+        }
+    }
+
+    // Not public: swapping out a whole material would unload textures and shaders other materials may share.
+    fn materials_mut(&mut self) -> &mut [Material<'w>] {
+        if self.inner.materials.is_null() || self.inner.material_count == 0 {
+            return &mut [];
+        }
+
+        unsafe {
+            return slice::from_raw_parts_mut(self.inner.materials as *mut Material, self.inner.material_count as usize);
+        }
+    }
+
+    // Panics if material_index is out of range.
+    // Unloads whatever texture was in the map before, unless it was raylib's default or any material still uses it.
+    pub fn set_material_texture(&mut self, material_index: usize, map: MaterialMapIndex, texture: Texture2D<'w>) {
+        assert!(material_index < self.inner.material_count as usize, "material index out of range");
+
+        let old_texture = self.materials_mut()[material_index].replace_texture(map, texture);
+
+        if !self.materials().iter().any(|material| material.uses_texture(old_texture.id)) {
+            unload_material_texture(old_texture);
+        }
+    }
+
+    // Panics if material_index is out of range.
+    // Unloads the shader the material had before, unless it was raylib's default or another material still uses it.
+    pub fn set_material_shader(&mut self, material_index: usize, shader: Shader<'w>) {
+        assert!(material_index < self.inner.material_count as usize, "material index out of range");
+
+        let old_shader = self.materials_mut()[material_index].replace_shader(shader);
+
+        if !self.materials().iter().any(|material| material.inner.shader.id == old_shader.id) {
+            unload_material_shader(old_shader);
+        }
+    }
+
+    // Index into materials() used by the mesh at mesh_index. None if mesh_index is out of range.
+    pub fn mesh_material(&self, mesh_index: usize) -> Option<usize> {
+        let indices;
+
+        unsafe {
+            indices = raylib_slice(self.inner.mesh_material as *const c_int, self.inner.mesh_count as usize);
+        }
+
+        return indices.get(mesh_index).map(|index| *index as usize);
+    }
+
+    // Panics if either index is out of range.
+    pub fn set_mesh_material(&mut self, mesh_index: usize, material_index: usize) {
+        assert!(mesh_index < self.inner.mesh_count as usize, "mesh index out of range");
+        assert!(material_index < self.inner.material_count as usize, "material index out of range");
+
+        unsafe {
+            SetModelMeshMaterial(&mut self.inner, mesh_index as c_int, material_index as c_int);
+        }
+    }
+
+    pub fn bone_count(&self) -> i32 {
+        return self.inner.bone_count;
+    }
+
+    pub fn bounding_box(&self) -> BoundingBox {
+        let result;
+
+        unsafe {
+            result = GetModelBoundingBox(self.to_cmodel());
+        }
+
+        return result;
+    }
+
+    pub fn is_animation_valid(&self, anim: &ModelAnimation) -> bool {
+        let result;
+
+        unsafe {
+            result = IsModelAnimationValid(self.to_cmodel(), anim.to_cmodel_animation());
+        }
+
+        return result;
+    }
+
+    // Poses the model's meshes at frame, wrapping around in either direction.
+    pub fn update_animation(&mut self, anim: &ModelAnimation, frame: i32) -> Result<(), Error> {
+        if anim.frame_count() <= 0 || !self.is_animation_valid(anim) {
+            return Err(Error::InvalidAnimation(anim.name()));
+        }
+
+        unsafe {
+            UpdateModelAnimation(self.to_cmodel(), anim.to_cmodel_animation(), frame.rem_euclid(anim.frame_count()) as c_int);
+        }
+
+        return Ok(());
+    }
+}
+
+impl Drop for Model<'_> {
+    fn drop(&mut self) {
+        // UnloadModel only frees the materials' maps, so unload what's in them first.
+        // Several maps may share a texture, so each is only unloaded once.
+        let mut unloaded_textures = Vec::new();
+        let mut unloaded_shaders = Vec::new();
+
+        unsafe {
+            for material in self.materials() {
+                let shader = material.inner.shader.clone();
+
+                if shader.id != rlGetShaderIdDefault() && !unloaded_shaders.contains(&shader.id) {
+                    unloaded_shaders.push(shader.id);
+                    UnloadShader(shader);
+                }
+
+                for map in material.maps() {
+                    let texture = map.texture.clone();
+
+                    if texture.id != 0 && texture.id != rlGetTextureIdDefault() && !unloaded_textures.contains(&texture.id) {
+                        unloaded_textures.push(texture.id);
+                        UnloadTexture(texture);
+                    }
+                }
+            }
+
+            UnloadModel(self.to_cmodel());
+        }
+    }
+}
+
+pub struct ModelAnimation {
+    inner: CModelAnimation,
+}
+
+impl ModelAnimation {
+    fn to_cmodel_animation(&self) -> CModelAnimation {
+        return self.inner.clone();
+    }
+
+    // Every animation in an .iqm, .gltf or .m3d file.
+    pub fn load(file_name: &str) -> Result<Vec<ModelAnimation>, Error> {
+        let converted_file_name = CString::new(file_name)?;
+        let mut anim_count: c_int = 0;
+        let result;
+
+        unsafe {
+            result = LoadModelAnimations(converted_file_name.as_ptr(), &mut anim_count);
+        }
+
+        if result.is_null() {
+            return Err(Error::ResourceLoadFailed(file_name.to_string()));
+        }
+
+        unsafe {
+            return Ok(take_raylib_array(result, anim_count, |anim| ModelAnimation { inner: anim }));
+        }
+    }
+
+    pub fn name(&self) -> String {
+        return raylib_name(&self.inner.name);
+    }
+
+    pub fn frame_count(&self) -> i32 {
+        return self.inner.frame_count;
+    }
+
+    pub fn bone_count(&self) -> i32 {
+        return self.inner.bone_count;
+    }
+}
+
+impl Drop for ModelAnimation {
+    fn drop(&mut self) {
+        unsafe {
+            UnloadModelAnimation(self.to_cmodel_animation());
+        }
+    }
+}
+
+// Models, meshes and materials all live partly on the GPU, hence the window.
+impl WindowHandle {
+    // Supports .obj, .iqm, .gltf/.glb, .vox and .m3d.
+    pub fn load_model(&self, file_name: &str) -> Result<Model<'_>, Error> {
+        let converted_file_name = CString::new(file_name)?;
+        let result;
+
+        unsafe {
+            result = LoadModel(converted_file_name.as_ptr());
+        }
+
+        return Model::from_cmodel(result, file_name);
+    }
+
+    // The model takes ownership of the mesh, and gets a default material.
+    pub fn load_model_from_mesh<'w>(&'w self, mesh: Mesh<'w>) -> Result<Model<'w>, Error> {
+        let mesh = ManuallyDrop::new(mesh);
+        let result;
+        let valid;
+
+        unsafe {
+            result = LoadModelFromMesh(mesh.to_cmesh());
+            valid = IsModelValid(result.clone());
+        }
+
+        if !valid {
+            // Only free what LoadModelFromMesh allocated; the mesh itself goes back to its own Drop.
+            unsafe {
+                if !result.materials.is_null() {
+                    UnloadMaterial((*result.materials).clone());
+                }
+
+                MemFree(result.meshes as *mut c_void);
+                MemFree(result.materials as *mut c_void);
+                MemFree(result.mesh_material as *mut c_void);
+            }
+
+            drop(ManuallyDrop::into_inner(mesh));
+
+            return Err(Error::ResourceLoadFailed("mesh".to_string()));
+        }
+
+        return Ok(Model { inner: result, _window: PhantomData });
+    }
+
+    pub fn load_material_default(&self) -> Material<'_> {
+        let result;
+
+        unsafe {
+            result = LoadMaterialDefault();
+        }
+
+        return Material { inner: result, _window: PhantomData };
+    }
+
+    // Every material in an .mtl file.
+    pub fn load_materials(&self, file_name: &str) -> Result<Vec<Material<'_>>, Error> {
+        let converted_file_name = CString::new(file_name)?;
+        let mut material_count: c_int = 0;
+        let result;
+
+        unsafe {
+            result = LoadMaterials(converted_file_name.as_ptr(), &mut material_count);
+        }
+
+        if result.is_null() {
+            return Err(Error::ResourceLoadFailed(file_name.to_string()));
+        }
+
+        let materials;
+
+        unsafe {
+            materials = take_raylib_array(result, material_count, |material| Material { inner: material, _window: PhantomData });
+        }
+
+        let all_valid = materials.iter().all(|material| unsafe { IsMaterialValid(material.to_cmaterial()) });
+
+        if !all_valid {
+            return Err(Error::ResourceLoadFailed(file_name.to_string()));
+        }
+
+        return Ok(materials);
+    }
+
+    pub fn gen_mesh_poly(&self, sides: i32, radius: f32) -> Mesh<'_> {
+        let result;
+
+        unsafe {
+            result = GenMeshPoly(sides as c_int, radius as c_float);
+        }
+
+        return Mesh { inner: result, _window: PhantomData };
+    }
+
+    pub fn gen_mesh_plane(&self, width: f32, length: f32, res_x: i32, res_z: i32) -> Mesh<'_> {
+        let result;
+
+        unsafe {
+            result = GenMeshPlane(width as c_float, length as c_float, res_x as c_int, res_z as c_int);
+        }
+
+        return Mesh { inner: result, _window: PhantomData };
+    }
+
+    pub fn gen_mesh_cube(&self, width: f32, height: f32, length: f32) -> Mesh<'_> {
+        let result;
+
+        unsafe {
+            result = GenMeshCube(width as c_float, height as c_float, length as c_float);
+        }
+
+        return Mesh { inner: result, _window: PhantomData };
+    }
+
+    pub fn gen_mesh_sphere(&self, radius: f32, rings: i32, slices: i32) -> Mesh<'_> {
+        let result;
+
+        unsafe {
+            result = GenMeshSphere(radius as c_float, rings as c_int, slices as c_int);
+        }
+
+        return Mesh { inner: result, _window: PhantomData };
+    }
+
+    pub fn gen_mesh_hemisphere(&self, radius: f32, rings: i32, slices: i32) -> Mesh<'_> {
+        let result;
+
+        unsafe {
+            result = GenMeshHemiSphere(radius as c_float, rings as c_int, slices as c_int);
+        }
+
+        return Mesh { inner: result, _window: PhantomData };
+    }
+
+    pub fn gen_mesh_cylinder(&self, radius: f32, height: f32, slices: i32) -> Mesh<'_> {
+        let result;
+
+        unsafe {
+            result = GenMeshCylinder(radius as c_float, height as c_float, slices as c_int);
+        }
+
+        return Mesh { inner: result, _window: PhantomData };
+    }
+
+    pub fn gen_mesh_cone(&self, radius: f32, height: f32, slices: i32) -> Mesh<'_> {
+        let result;
+
+        unsafe {
+            result = GenMeshCone(radius as c_float, height as c_float, slices as c_int);
+        }
+
+        return Mesh { inner: result, _window: PhantomData };
+    }
+
+    pub fn gen_mesh_torus(&self, radius: f32, size: f32, rad_seg: i32, sides: i32) -> Mesh<'_> {
+        let result;
+
+        unsafe {
+            result = GenMeshTorus(radius as c_float, size as c_float, rad_seg as c_int, sides as c_int);
+        }
+
+        return Mesh { inner: result, _window: PhantomData };
+    }
+
+    pub fn gen_mesh_knot(&self, radius: f32, size: f32, rad_seg: i32, sides: i32) -> Mesh<'_> {
+        let result;
+
+        unsafe {
+            result = GenMeshKnot(radius as c_float, size as c_float, rad_seg as c_int, sides as c_int);
+        }
+
+        return Mesh { inner: result, _window: PhantomData };
+    }
+
+    // Brighter pixels in heightmap are higher. size is the extent of the whole mesh.
+    pub fn gen_mesh_heightmap(&self, heightmap: &Image, size: Vector3) -> Mesh<'_> {
+        let result;
+
+        unsafe {
+            result = GenMeshHeightmap(heightmap.to_cimage(), size);
+        }
+
+        return Mesh { inner: result, _window: PhantomData };
+    }
+
+    // Every white pixel in cubicmap becomes a cube of cube_size.
+    pub fn gen_mesh_cubicmap(&self, cubicmap: &Image, cube_size: Vector3) -> Mesh<'_> {
+        let result;
+
+        unsafe {
+            result = GenMeshCubicmap(cubicmap.to_cimage(), cube_size);
+        }
+
+        return Mesh { inner: result, _window: PhantomData };
+    }
+}
+
+/*===================================
+            3D collisions.
+=====================================*/
+
+pub fn check_collision_spheres(center1: Vector3, radius1: f32, center2: Vector3, radius2: f32) -> bool {
+    let result;
+
+    unsafe {
+        result = CheckCollisionSpheres(center1, radius1 as c_float, center2, radius2 as c_float);
+    }
+
+    return result;
+}
+
+pub fn check_collision_boxes(box1: BoundingBox, box2: BoundingBox) -> bool {
+    let result;
+
+    unsafe {
+        result = CheckCollisionBoxes(box1, box2);
+    }
+
+    return result;
+}
+
+pub fn check_collision_box_sphere(bbox: BoundingBox, center: Vector3, radius: f32) -> bool {
+    let result;
+
+    unsafe {
+        result = CheckCollisionBoxSphere(bbox, center, radius as c_float);
+    }
+
+    return result;
+}
+
+pub fn get_ray_collision_sphere(ray: Ray, center: Vector3, radius: f32) -> Option<RayCollision> {
+    let result;
+
+    unsafe {
+        result = GetRayCollisionSphere(ray, center, radius as c_float);
+    }
+
+    return RayCollision::from_cray_collision(result);
+}
+
+pub fn get_ray_collision_box(ray: Ray, bbox: BoundingBox) -> Option<RayCollision> {
+    let result;
+
+    unsafe {
+        result = GetRayCollisionBox(ray, bbox);
+    }
+
+    return RayCollision::from_cray_collision(result);
+}
+
+// transform places the mesh in the world, as when drawing it.
+pub fn get_ray_collision_mesh(ray: Ray, mesh: &Mesh, transform: Matrix) -> Option<RayCollision> {
+    let result;
+
+    unsafe {
+        result = GetRayCollisionMesh(ray, mesh.to_cmesh(), transform);
+    }
+
+    return RayCollision::from_cray_collision(result);
+}
+
+// The nearest hit across all of the model's meshes, using the model's own transform.
+pub fn get_ray_collision_model(ray: Ray, model: &Model) -> Option<RayCollision> {
+    let transform = model.transform();
+
+    return model.meshes().iter()
+        .filter_map(|mesh| get_ray_collision_mesh(ray, mesh, transform))
+        .min_by(|a, b| a.distance.partial_cmp(&b.distance).unwrap_or(std::cmp::Ordering::Equal));
+}
+
+pub fn get_ray_collision_triangle(ray: Ray, p1: Vector3, p2: Vector3, p3: Vector3) -> Option<RayCollision> {
+    let result;
+
+    unsafe {
+        result = GetRayCollisionTriangle(ray, p1, p2, p3);
+    }
+
+    return RayCollision::from_cray_collision(result);
+}
+
+// The points should go around the quad in order.
+pub fn get_ray_collision_quad(ray: Ray, p1: Vector3, p2: Vector3, p3: Vector3, p4: Vector3) -> Option<RayCollision> {
+    let result;
+
+    unsafe {
+        result = GetRayCollisionQuad(ray, p1, p2, p3, p4);
+    }
+
+    return RayCollision::from_cray_collision(result);
+}
+
+/*===================================
+              Shaders.
+=====================================*/
+
+pub struct Shader<'w> {
+    inner: CShader,
+    locations: RefCell<HashMap<String, c_int>>,
+    _window: PhantomData<&'w WindowHandle>,
+}
+
+impl<'w> Shader<'w> {
+    fn to_cshader(&self) -> CShader {
+        return self.inner.clone();
+    }
+
+    fn from_cshader(shader: CShader, source: &str, any_given: bool) -> Result<Shader<'w>, Error> {
+        // A stage that doesn't compile leaves no program at all, and a failed link falls back to raylib's default.
+        let failed;
+
+        unsafe {
+            failed = shader.locs.is_null() || (any_given && shader.id == rlGetShaderIdDefault());
+        }
+
+        if failed {
+            return Err(Error::ResourceLoadFailed(source.to_string()));
+        }
+
+        return Ok(Shader { inner: shader, locations: RefCell::new(HashMap::new()), _window: PhantomData });
+    }
+
+    // None if the shader has no active uniform by that name. Lookups are cached.
+    pub fn get_location(&self, uniform_name: &str) -> Option<i32> {
+        if let Some(location) = self.locations.borrow().get(uniform_name) {
+            return if *location < 0 { None } else { Some(*location) };
+        }
+
+        // A name containing a NUL byte can't match any uniform.
+        let location = match CString::new(uniform_name) {
+            Ok(converted_name) => unsafe { GetShaderLocation(self.to_cshader(), converted_name.as_ptr()) },
+            Err(_) => -1,
+        };
+
+        self.locations.borrow_mut().insert(uniform_name.to_string(), location);
+
+        return if location < 0 { None } else { Some(location) };
+    }
+
+    // Setting a uniform the shader doesn't have does nothing, like in raylib.
+    pub fn set_uniform<T: ShaderValue>(&self, uniform_name: &str, value: T) {
+        if let Some(location) = self.get_location(uniform_name) {
+            self.set_uniform_at(location, value);
+        }
+    }
+
+    pub fn set_uniform_at<T: ShaderValue>(&self, location: i32, value: T) {
+        value.set(self, location as c_int);
+    }
+}
+
+impl Drop for Shader<'_> {
+    fn drop(&mut self) {
+        unsafe {
+            UnloadShader(self.to_cshader());
+        }
+    }
+}
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+// Anything that can be handed to Shader::set_uniform. Sealed, since each type maps to a fixed uniform type.
+pub trait ShaderValue: sealed::Sealed {
+    #[doc(hidden)]
+    fn set(&self, shader: &Shader, location: c_int);
+}
+
+// Raylib's uniform type codes.
+const SHADER_UNIFORM_FLOAT: c_int = 0;
+const SHADER_UNIFORM_VEC2: c_int = 1;
+const SHADER_UNIFORM_VEC3: c_int = 2;
+const SHADER_UNIFORM_VEC4: c_int = 3;
+const SHADER_UNIFORM_INT: c_int = 4;
+
+fn set_shader_value<T>(shader: &Shader, location: c_int, value: &T, uniform_type: c_int) {
+    unsafe {
+        SetShaderValue(shader.to_cshader(), location, value as *const T as *const c_void, uniform_type);
+    }
+}
+
+impl sealed::Sealed for f32 {}
+impl ShaderValue for f32 {
+    fn set(&self, shader: &Shader, location: c_int) {
+        set_shader_value(shader, location, self, SHADER_UNIFORM_FLOAT);
+    }
+}
+
+impl sealed::Sealed for i32 {}
+impl ShaderValue for i32 {
+    fn set(&self, shader: &Shader, location: c_int) {
+        set_shader_value(shader, location, self, SHADER_UNIFORM_INT);
+    }
+}
+
+impl sealed::Sealed for Vector2 {}
+impl ShaderValue for Vector2 {
+    fn set(&self, shader: &Shader, location: c_int) {
+        set_shader_value(shader, location, self, SHADER_UNIFORM_VEC2);
+    }
+}
+
+impl sealed::Sealed for Vector3 {}
+impl ShaderValue for Vector3 {
+    fn set(&self, shader: &Shader, location: c_int) {
+        set_shader_value(shader, location, self, SHADER_UNIFORM_VEC3);
+    }
+}
+
+impl sealed::Sealed for Vector4 {}
+impl ShaderValue for Vector4 {
+    fn set(&self, shader: &Shader, location: c_int) {
+        set_shader_value(shader, location, self, SHADER_UNIFORM_VEC4);
+    }
+}
+
+// As a vec4 with each channel between 0.0 and 1.0.
+impl sealed::Sealed for Color {}
+impl ShaderValue for Color {
+    fn set(&self, shader: &Shader, location: c_int) {
+        let normalized = Vector4::new(self.r as f32 / 255.0, self.g as f32 / 255.0, self.b as f32 / 255.0, self.a as f32 / 255.0);
+
+        normalized.set(shader, location);
+    }
+}
+
+impl sealed::Sealed for Matrix {}
+impl ShaderValue for Matrix {
+    fn set(&self, shader: &Shader, location: c_int) {
+        unsafe {
+            SetShaderValueMatrix(shader.to_cshader(), location, *self);
+        }
+    }
+}
+
+// As a sampler2D.
+impl sealed::Sealed for &Texture2D<'_> {}
+impl ShaderValue for &Texture2D<'_> {
+    fn set(&self, shader: &Shader, location: c_int) {
+        unsafe {
+            SetShaderValueTexture(shader.to_cshader(), location, self.to_ctexture());
+        }
+    }
+}
+
+impl WindowHandle {
+    // Either stage may be left as None to use raylib's default for it.
+    pub fn load_shader(&self, vs_file_name: Option<&str>, fs_file_name: Option<&str>) -> Result<Shader<'_>, Error> {
+        // Raylib quietly falls back to its default for a stage whose file can't be read, so read them here.
+        let read = |file_name: &str| fs::read_to_string(file_name).map_err(|_| Error::ResourceLoadFailed(file_name.to_string()));
+        let vs_code = vs_file_name.map(read).transpose()?;
+        let fs_code = fs_file_name.map(read).transpose()?;
+
+        let source = vs_file_name.into_iter().chain(fs_file_name).collect::<Vec<&str>>().join(", ");
+
+        return self.load_shader_code(vs_code.as_deref(), fs_code.as_deref(), &source);
+    }
+
+    // Takes GLSL source rather than file names.
+    pub fn load_shader_from_memory(&self, vs_code: Option<&str>, fs_code: Option<&str>) -> Result<Shader<'_>, Error> {
+        return self.load_shader_code(vs_code, fs_code, "shader code");
+    }
+
+    fn load_shader_code(&self, vs_code: Option<&str>, fs_code: Option<&str>, source: &str) -> Result<Shader<'_>, Error> {
+        let converted_vs = vs_code.map(CString::new).transpose()?;
+        let converted_fs = fs_code.map(CString::new).transpose()?;
+        let result;
+
+        unsafe {
+            result = LoadShaderFromMemory(
+                converted_vs.as_ref().map_or(std::ptr::null(), |vs| vs.as_ptr()),
+                converted_fs.as_ref().map_or(std::ptr::null(), |fs| fs.as_ptr()),
+            );
+        }
+
+        return Shader::from_cshader(result, source, vs_code.is_some() || fs_code.is_some());
+    }
+}
+
+pub struct ShaderModeContext<'a, 'w> {
+    drawing: &'a mut DrawingContext<'w>,
+    // The outer guard still borrows its shader, so this copy can't dangle.
+    outer_shader: Option<CShader>,
+    _shader: &'a Shader<'w>,
+}
+
+impl<'a, 'w> Deref for ShaderModeContext<'a, 'w> {
+    type Target = DrawingContext<'w>;
+
+    fn deref(&self) -> &DrawingContext<'w> {
+        return self.drawing;
+    }
+}
+
+impl<'a, 'w> DerefMut for ShaderModeContext<'a, 'w> {
+    fn deref_mut(&mut self) -> &mut DrawingContext<'w> {
+        return self.drawing;
+    }
+}
+
+impl<'a, 'w> Drop for ShaderModeContext<'a, 'w> {
+    fn drop(&mut self) {
+        let outer_shader = self.outer_shader.take();
+
+        unsafe {
+            EndShaderMode();
+
+            if let Some(shader) = outer_shader.clone() {
+                BeginShaderMode(shader);
+            }
+        }
+
+        self.drawing.shader = outer_shader;
+    }
+}
+
+/*===================================
+        Blending and scissoring.
+=====================================*/
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BlendMode {
+    Alpha,              // The default.
+    Additive,
+    Multiplied,
+    AddColors,
+    SubtractColors,
+    AlphaPremultiply,
+    Custom { src: BlendFactor, dst: BlendFactor, equation: BlendEquation },
+    CustomSeparate {
+        src_rgb: BlendFactor,
+        dst_rgb: BlendFactor,
+        src_alpha: BlendFactor,
+        dst_alpha: BlendFactor,
+        equation_rgb: BlendEquation,
+        equation_alpha: BlendEquation,
+    },
+}
+
+impl BlendMode {
+    fn to_blend_code(&self) -> c_int {
+        match self {
+            BlendMode::Alpha                 => 0,
+            BlendMode::Additive              => 1,
+            BlendMode::Multiplied            => 2,
+            BlendMode::AddColors             => 3,
+            BlendMode::SubtractColors        => 4,
+            BlendMode::AlphaPremultiply      => 5,
+            BlendMode::Custom { .. }         => 6,
+            BlendMode::CustomSeparate { .. } => 7,
+        }
+    }
+
+    fn apply(&self) {
+        unsafe {
+            match *self {
+                BlendMode::Custom { src, dst, equation } => {
+                    rlSetBlendFactors(src.to_gl_code(), dst.to_gl_code(), equation.to_gl_code());
+                },
+                BlendMode::CustomSeparate { src_rgb, dst_rgb, src_alpha, dst_alpha, equation_rgb, equation_alpha } => {
+                    rlSetBlendFactorsSeparate(src_rgb.to_gl_code(), dst_rgb.to_gl_code(), src_alpha.to_gl_code(), dst_alpha.to_gl_code(), equation_rgb.to_gl_code(), equation_alpha.to_gl_code());
+                },
+                _ => {},
+            }
+
+            BeginBlendMode(self.to_blend_code());
+        }
+    }
+}
+
+// OpenGL blend factors, for custom blend modes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BlendFactor {
+    Zero,
+    One,
+    SrcColor,
+    OneMinusSrcColor,
+    SrcAlpha,
+    OneMinusSrcAlpha,
+    DstAlpha,
+    OneMinusDstAlpha,
+    DstColor,
+    OneMinusDstColor,
+    SrcAlphaSaturate,
+    ConstantColor,
+    OneMinusConstantColor,
+    ConstantAlpha,
+    OneMinusConstantAlpha,
+}
+
+impl BlendFactor {
+    fn to_gl_code(&self) -> c_int {
+        match self {
+            BlendFactor::Zero                  => 0,
+            BlendFactor::One                   => 1,
+            BlendFactor::SrcColor              => 0x0300,
+            BlendFactor::OneMinusSrcColor      => 0x0301,
+            BlendFactor::SrcAlpha              => 0x0302,
+            BlendFactor::OneMinusSrcAlpha      => 0x0303,
+            BlendFactor::DstAlpha              => 0x0304,
+            BlendFactor::OneMinusDstAlpha      => 0x0305,
+            BlendFactor::DstColor              => 0x0306,
+            BlendFactor::OneMinusDstColor      => 0x0307,
+            BlendFactor::SrcAlphaSaturate      => 0x0308,
+            BlendFactor::ConstantColor         => 0x8001,
+            BlendFactor::OneMinusConstantColor => 0x8002,
+            BlendFactor::ConstantAlpha         => 0x8003,
+            BlendFactor::OneMinusConstantAlpha => 0x8004,
+        }
+    }
+}
+
+// OpenGL blend equations, for custom blend modes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BlendEquation {
+    Add,
+    Subtract,
+    ReverseSubtract,
+    Min,
+    Max,
+}
+
+impl BlendEquation {
+    fn to_gl_code(&self) -> c_int {
+        match self {
+            BlendEquation::Add             => 0x8006,
+            BlendEquation::Min             => 0x8007,
+            BlendEquation::Max             => 0x8008,
+            BlendEquation::Subtract        => 0x800A,
+            BlendEquation::ReverseSubtract => 0x800B,
+        }
+    }
+}
+
+pub struct BlendModeContext<'a, 'w> {
+    drawing: &'a mut DrawingContext<'w>,
+    outer_mode: Option<BlendMode>,
+}
+
+impl<'a, 'w> Deref for BlendModeContext<'a, 'w> {
+    type Target = DrawingContext<'w>;
+
+    fn deref(&self) -> &DrawingContext<'w> {
+        return self.drawing;
+    }
+}
+
+impl<'a, 'w> DerefMut for BlendModeContext<'a, 'w> {
+    fn deref_mut(&mut self) -> &mut DrawingContext<'w> {
+        return self.drawing;
+    }
+}
+
+impl<'a, 'w> Drop for BlendModeContext<'a, 'w> {
+    fn drop(&mut self) {
+        unsafe {
+            EndBlendMode();
+        }
+
+        if let Some(mode) = self.outer_mode {
+            mode.apply();
+        }
+
+        self.drawing.blend_mode = self.outer_mode;
+    }
+}
+
+pub struct ScissorModeContext<'a, 'w> {
+    drawing: &'a mut DrawingContext<'w>,
+    outer_area: Option<Rectangle>,
+}
+
+impl<'a, 'w> Deref for ScissorModeContext<'a, 'w> {
+    type Target = DrawingContext<'w>;
+
+    fn deref(&self) -> &DrawingContext<'w> {
+        return self.drawing;
+    }
+}
+
+impl<'a, 'w> DerefMut for ScissorModeContext<'a, 'w> {
+    fn deref_mut(&mut self) -> &mut DrawingContext<'w> {
+        return self.drawing;
+    }
+}
+
+impl<'a, 'w> Drop for ScissorModeContext<'a, 'w> {
+    fn drop(&mut self) {
+        unsafe {
+            EndScissorMode();
+
+            if let Some(area) = self.outer_area {
+                BeginScissorMode(area.x as c_int, area.y as c_int, area.width as c_int, area.height as c_int);
+            }
+        }
+
+        self.drawing.scissor_area = self.outer_area;
+    }
+}
+
+/*===================================
+               Audio.
+=====================================*/
+
+// Like the window, raylib's audio state is global, so only one AudioDevice may be alive at a time.
+static AUDIO_DEVICE_OPEN: AtomicBool = AtomicBool::new(false);
+
+// Sounds, music and streams borrow the device, so they can't outlive it.
+pub struct AudioDevice {
+    _marker: PhantomData<*const ()>,
+}
+
+pub fn init_audio_device() -> Result<AudioDevice, Error> {
+    if AUDIO_DEVICE_OPEN.swap(true, Ordering::SeqCst) {
+        return Err(Error::AudioDeviceAlreadyOpen);
+    }
+
+    unsafe {
+        InitAudioDevice();
+
+        if !IsAudioDeviceReady() {
+            AUDIO_DEVICE_OPEN.store(false, Ordering::SeqCst);
+            return Err(Error::AudioDeviceFailed);
+        }
+    }
+
+    return Ok(AudioDevice { _marker: PhantomData });
+}
+
+impl AudioDevice {
+    // Between 0.0 and 1.0.
+    pub fn set_master_volume(&self, volume: f32) {
+        unsafe {
+            SetMasterVolume(volume as c_float);
+        }
+    }
+
+    pub fn get_master_volume(&self) -> f32 {
+        let result;
+
+        unsafe {
+            result = GetMasterVolume();
+        }
+
+        return result;
+    }
+
+    pub fn load_sound(&self, file_name: &str) -> Result<Sound<'_>, Error> {
+        let converted_file_name = CString::new(file_name)?;
+        let result;
+
+        unsafe {
+            result = LoadSound(converted_file_name.as_ptr());
+        }
+
+        return Sound::from_csound(result, file_name);
+    }
+
+    pub fn load_sound_from_wave(&self, wave: &Wave) -> Result<Sound<'_>, Error> {
+        let result;
+
+        unsafe {
+            result = LoadSoundFromWave(wave.to_cwave());
+        }
+
+        return Sound::from_csound(result, "wave");
+    }
+
+    // An alias shares the source's sample data but plays independently, so the same sound can overlap itself.
+    pub fn load_sound_alias<'s>(&self, source: &'s Sound<'_>) -> Result<SoundAlias<'s>, Error> {
+        let result;
+
+        unsafe {
+            result = LoadSoundAlias(source.to_csound());
+        }
+
+        let sound = Sound::from_csound(result, "sound alias")?;
+
+        return Ok(SoundAlias { sound: ManuallyDrop::new(sound), _source: PhantomData });
+    }
+}
+
+impl Drop for AudioDevice {
+    fn drop(&mut self) {
+        unsafe {
+            CloseAudioDevice();
+        }
+
+        AUDIO_DEVICE_OPEN.store(false, Ordering::SeqCst);
+    }
+}
+
+// Miniaudio's limits, which raylib converts waves with.
+const MAX_SAMPLE_RATE: u32 = 384000;
+const MAX_CHANNELS: u32 = 254;
+
+// Audio samples in main memory. Loading one does not need an audio device.
+pub struct Wave {
+    inner: CWave,
+}
+
+impl Wave {
+    fn to_cwave(&self) -> CWave {
+        return self.inner.clone();
+    }
+
+    fn from_cwave(wave: CWave, source: &str) -> Result<Wave, Error> {
+        let valid;
+
+        unsafe {
+            valid = IsWaveValid(wave.clone());
+        }
+
+        if !valid {
+            return Err(Error::ResourceLoadFailed(source.to_string()));
+        }
+
+        return Ok(Wave { inner: wave });
+    }
+
+    // Supports .wav, .ogg, .mp3, .flac, .qoa and .xm/.mod.
+    pub fn load(file_name: &str) -> Result<Wave, Error> {
+        let converted_file_name = CString::new(file_name)?;
+        let result;
+
+        unsafe {
+            result = LoadWave(converted_file_name.as_ptr());
+        }
+
+        return Wave::from_cwave(result, file_name);
+    }
+
+    // file_type is the extension, including the dot, e.g. ".wav".
+    pub fn load_from_memory(file_type: &str, data: &[u8]) -> Result<Wave, Error> {
+        let converted_file_type = CString::new(file_type)?;
+        let result;
+
+        unsafe {
+            result = LoadWaveFromMemory(converted_file_type.as_ptr(), data.as_ptr(), data.len() as c_int);
+        }
+
+        return Wave::from_cwave(result, file_type);
+    }
+
+    pub fn frame_count(&self) -> u32 {
+        return self.inner.frame_count;
+    }
+
+    pub fn sample_rate(&self) -> u32 {
+        return self.inner.sample_rate;
+    }
+
+    // In bits: 8, 16 or 32.
+    pub fn sample_size(&self) -> u32 {
+        return self.inner.sample_size;
+    }
+
+    pub fn channels(&self) -> u32 {
+        return self.inner.channels;
+    }
+
+    // Keeps frames from init_frame up to, but not including, final_frame.
+    // Raylib ignores the crop if final_frame is past the end or not after init_frame.
+    pub fn crop(&mut self, init_frame: u32, final_frame: u32) {
+        unsafe {
+            WaveCrop(&mut self.inner, init_frame as c_int, final_frame as c_int);
+        }
+    }
+
+    // Resamples and converts in place. sample_size is in bits: 8, 16 or 32.
+    // The wave is left as is if any of the three is out of range.
+    pub fn format(&mut self, sample_rate: u32, sample_size: u32, channels: u32) -> Result<(), Error> {
+        let valid = (1..=MAX_SAMPLE_RATE).contains(&sample_rate)
+            && matches!(sample_size, 8 | 16 | 32)
+            && (1..=MAX_CHANNELS).contains(&channels);
+
+        if !valid {
+            return Err(Error::InvalidAudioFormat(sample_rate, sample_size, channels));
+        }
+
+        unsafe {
+            WaveFormat(&mut self.inner, sample_rate as c_int, sample_size as c_int, channels as c_int);
+        }
+
+        return Ok(());
+    }
+
+    // Interleaved if there is more than one channel.
+    // Returns None unless samples are 32-bit floats, see format and load_samples.
+    pub fn samples(&self) -> Option<&[f32]> {
+        if self.inner.sample_size != 32 || self.inner.data.is_null() {
+            return None;
+        }
+
+        let len = (self.inner.frame_count * self.inner.channels) as usize;
+
+        unsafe {
+            return Some(slice::from_raw_parts(self.inner.data as *const f32, len));
+        }
+    }
+
+    pub fn samples_mut(&mut self) -> Option<&mut [f32]> {
+        if self.inner.sample_size != 32 || self.inner.data.is_null() {
+            return None;
+        }
+
+        let len = (self.inner.frame_count * self.inner.channels) as usize;
+
+        unsafe {
+            return Some(slice::from_raw_parts_mut(self.inner.data as *mut f32, len));
+        }
+    }
+
+    // Copies the samples out as floats between -1.0 and 1.0, whatever the sample size.
+    pub fn load_samples(&self) -> Vec<f32> {
+        let len = (self.inner.frame_count * self.inner.channels) as usize;
+        let mut result = Vec::with_capacity(len);
+
+        unsafe {
+            let samples = LoadWaveSamples(self.to_cwave());
+
+            if !samples.is_null() {
+                result.extend_from_slice(slice::from_raw_parts(samples, len));
+                UnloadWaveSamples(samples);
+            }
+        }
+
+        return result;
+    }
+
+    // Supports .wav, .qoa and .raw.
+    pub fn export(&self, file_name: &str) -> Result<(), Error> {
+        let converted_file_name = CString::new(file_name)?;
+        let success;
+
+        unsafe {
+            success = ExportWave(self.to_cwave(), converted_file_name.as_ptr());
+        }
+
+        if !success {
+            return Err(Error::ResourceExportFailed(file_name.to_string()));
+        }
+
+        return Ok(());
+    }
+
+    // Writes the samples out as a C header.
+    pub fn export_as_code(&self, file_name: &str) -> Result<(), Error> {
+        let converted_file_name = CString::new(file_name)?;
+        let success;
+
+        unsafe {
+            success = ExportWaveAsCode(self.to_cwave(), converted_file_name.as_ptr());
+        }
+
+        if !success {
+            return Err(Error::ResourceExportFailed(file_name.to_string()));
+        }
+
+        return Ok(());
+    }
+}
+
+impl Clone for Wave {
+    fn clone(&self) -> Wave {
+        let result;
+
+        unsafe {
+            result = WaveCopy(self.to_cwave());
+        }
+
+        return Wave { inner: result };
+    }
+}
+
+impl Drop for Wave {
+    fn drop(&mut self) {
+        unsafe {
+            UnloadWave(self.to_cwave());
+        }
+    }
+}
+
+// Fully loaded into an audio buffer; use for short effects.
+pub struct Sound<'a> {
+    inner: CSound,
+    _device: PhantomData<&'a AudioDevice>,
+}
+
+impl<'a> Sound<'a> {
+    fn to_csound(&self) -> CSound {
+        return self.inner.clone();
+    }
+
+    fn from_csound(sound: CSound, source: &str) -> Result<Sound<'a>, Error> {
+        let valid;
+
+        unsafe {
+            valid = IsSoundValid(sound.clone());
+        }
+
+        if !valid {
+            return Err(Error::ResourceLoadFailed(source.to_string()));
+        }
+
+        return Ok(Sound { inner: sound, _device: PhantomData });
+    }
+
+    pub fn frame_count(&self) -> u32 {
+        return self.inner.frame_count;
+    }
+
+    // Restarts the sound if it is already playing.
+    pub fn play(&self) {
+        unsafe {
+            PlaySound(self.to_csound());
+        }
+    }
+
+    pub fn stop(&self) {
+        unsafe {
+            StopSound(self.to_csound());
+        }
+    }
+
+    pub fn pause(&self) {
+        unsafe {
+            PauseSound(self.to_csound());
+        }
+    }
+
+    pub fn resume(&self) {
+        unsafe {
+            ResumeSound(self.to_csound());
+        }
+    }
+
+    pub fn is_playing(&self) -> bool {
+        let result;
+
+        unsafe {
+            result = IsSoundPlaying(self.to_csound());
+        }
+
+        return result;
+    }
+
+    // 1.0 is full volume.
+    pub fn set_volume(&self, volume: f32) {
+        unsafe {
+            SetSoundVolume(self.to_csound(), volume as c_float);
+        }
+    }
+
+    // 1.0 is the original pitch.
+    pub fn set_pitch(&self, pitch: f32) {
+        unsafe {
+            SetSoundPitch(self.to_csound(), pitch as c_float);
+        }
+    }
+
+    // 0.0 is left, 0.5 center and 1.0 right.
+    pub fn set_pan(&self, pan: f32) {
+        unsafe {
+            SetSoundPan(self.to_csound(), pan as c_float);
+        }
+    }
+}
+
+impl Drop for Sound<'_> {
+    fn drop(&mut self) {
+        unsafe {
+            UnloadSound(self.to_csound());
+        }
+    }
+}
+
+// Borrows the sample data of the Sound it came from, so it can't outlive it.
+pub struct SoundAlias<'s> {
+    sound: ManuallyDrop<Sound<'s>>,
+    _source: PhantomData<&'s Sound<'s>>,
+}
+
+impl<'s> Deref for SoundAlias<'s> {
+    type Target = Sound<'s>;
+
+    fn deref(&self) -> &Sound<'s> {
+        return &self.sound;
+    }
+}
+
+impl<'s> Drop for SoundAlias<'s> {
+    fn drop(&mut self) {
+        unsafe {
+            UnloadSoundAlias(self.sound.to_csound());
+        }
+    }
+}
+
+/*===================================
+          Streaming audio.
+=====================================*/
+
+// Streamed from its source a chunk at a time; use for long tracks.
+pub struct Music<'a> {
+    inner: CMusic,
+    // Raylib decodes from memory-loaded music as it plays, so the data has to outlive it.
+    _data: Option<Vec<u8>>,
+    _device: PhantomData<&'a AudioDevice>,
+}
+
+impl<'a> Music<'a> {
+    fn to_cmusic(&self) -> CMusic {
+        return self.inner.clone();
+    }
+
+    fn from_cmusic(music: CMusic, data: Option<Vec<u8>>, source: &str) -> Result<Music<'a>, Error> {
+        let valid;
+
+        unsafe {
+            valid = IsMusicValid(music.clone());
+        }
+
+        if !valid {
+            return Err(Error::ResourceLoadFailed(source.to_string()));
+        }
+
+        return Ok(Music { inner: music, _data: data, _device: PhantomData });
+    }
+
+    pub fn play(&self) {
+        unsafe {
+            PlayMusicStream(self.to_cmusic());
+        }
+    }
+
+    pub fn stop(&self) {
+        unsafe {
+            StopMusicStream(self.to_cmusic());
+        }
+    }
+
+    pub fn pause(&self) {
+        unsafe {
+            PauseMusicStream(self.to_cmusic());
+        }
+    }
+
+    pub fn resume(&self) {
+        unsafe {
+            ResumeMusicStream(self.to_cmusic());
+        }
+    }
+
+    pub fn is_playing(&self) -> bool {
+        let result;
+
+        unsafe {
+            result = IsMusicStreamPlaying(self.to_cmusic());
+        }
+
+        return result;
+    }
+
+    // Refills the stream's buffers. Call it every frame while the music plays, or it will stutter.
+    pub fn update(&self) {
+        unsafe {
+            UpdateMusicStream(self.to_cmusic());
+        }
+    }
+
+    // Position is in seconds.
+    pub fn seek(&self, position: f32) {
+        unsafe {
+            SeekMusicStream(self.to_cmusic(), position as c_float);
+        }
+    }
 
-pub fn get_delta_time() -> f32 {
-    let out;
+    pub fn is_looping(&self) -> bool {
+        return self.inner.looping;
+    }
 
-    unsafe {
-        out = GetFrameTime();
+    // Music loops by default.
+    pub fn set_looping(&mut self, looping: bool) {
+        self.inner.looping = looping;
     }
 
-    return out as f32;
-}
+    pub fn set_volume(&self, volume: f32) {
+        unsafe {
+            SetMusicVolume(self.to_cmusic(), volume as c_float);
+        }
+    }
 
-pub fn get_mouse_position() -> Vector2 {
-    let out;
+    pub fn set_pitch(&self, pitch: f32) {
+        unsafe {
+            SetMusicPitch(self.to_cmusic(), pitch as c_float);
+        }
+    }
 
-    unsafe {
-        out = GetMousePosition();
+    pub fn set_pan(&self, pan: f32) {
+        unsafe {
+            SetMusicPan(self.to_cmusic(), pan as c_float);
+        }
     }
 
-    let converted_out = Vector2 {x: out.x, y: out.y};
+    // In seconds.
+    pub fn time_length(&self) -> f32 {
+        let result;
 
-    return converted_out;
-}
+        unsafe {
+            result = GetMusicTimeLength(self.to_cmusic());
+        }
 
-pub enum MouseButton {
-    Left,
-    Right,
-    Middle,
+        return result;
+    }
+
+    // In seconds.
+    pub fn time_played(&self) -> f32 {
+        let result;
+
+        unsafe {
+            result = GetMusicTimePlayed(self.to_cmusic());
+        }
+
+        return result;
+    }
 }
 
-impl MouseButton {
-    fn to_button_code(&self) -> c_int {
-        match self {
-            MouseButton::Left => 0,
-            MouseButton::Right => 1,
-            MouseButton::Middle => 2,
+impl Drop for Music<'_> {
+    fn drop(&mut self) {
+        unsafe {
+            UnloadMusicStream(self.to_cmusic());
         }
     }
 }
 
-pub fn is_mouse_button_pressed(button: MouseButton) -> bool {
-    let result;
+// Raylib's audio callbacks get no user data pointer, so each Rust callback is parked in one of a fixed number of
+// slots, and raylib is handed the trampoline for that slot.
+const AUDIO_CALLBACK_SLOTS: usize = 8;
 
-    let converted_button = button.to_button_code();
+type AudioCallback = Box<dyn FnMut(&mut [f32]) + Send>;
 
-    unsafe {
-        result = IsMouseButtonPressed(converted_button);
-    }
+struct AudioCallbackSlot {
+    channels: usize,
+    callback: AudioCallback,
+}
 
-    return result;
+static AUDIO_CALLBACKS: [Mutex<Option<AudioCallbackSlot>>; AUDIO_CALLBACK_SLOTS] = [const { Mutex::new(None) }; AUDIO_CALLBACK_SLOTS];
+
+const AUDIO_CALLBACK_TRAMPOLINES: [CAudioCallback; AUDIO_CALLBACK_SLOTS] = [
+    audio_callback_trampoline::<0>,
+    audio_callback_trampoline::<1>,
+    audio_callback_trampoline::<2>,
+    audio_callback_trampoline::<3>,
+    audio_callback_trampoline::<4>,
+    audio_callback_trampoline::<5>,
+    audio_callback_trampoline::<6>,
+    audio_callback_trampoline::<7>,
+];
+
+// Runs on the audio thread. A panic in the callback aborts the process.
+extern "C" fn audio_callback_trampoline<const SLOT: usize>(buffer_data: *mut c_void, frames: c_uint) {
+    let mut slot = match AUDIO_CALLBACKS[SLOT].lock() {
+        Ok(slot) => slot,
+        Err(poisoned) => poisoned.into_inner(),
+    };
+
+    if let Some(slot) = slot.as_mut() {
+        let samples;
+
+        unsafe {
+            samples = slice::from_raw_parts_mut(buffer_data as *mut f32, frames as usize * slot.channels);
+        }
+
+        (slot.callback)(samples);
+    }
 }
 
-pub fn get_screen_width() -> i32 {
-    let result;
+fn claim_audio_callback_slot(channels: usize, callback: AudioCallback) -> Result<usize, Error> {
+    for (index, slot) in AUDIO_CALLBACKS.iter().enumerate() {
+        let mut slot = match slot.lock() {
+            Ok(slot) => slot,
+            Err(poisoned) => poisoned.into_inner(),
+        };
 
-    unsafe {
-        result = GetScreenWidth();
+        if slot.is_none() {
+            *slot = Some(AudioCallbackSlot { channels, callback });
+            return Ok(index);
+        }
     }
 
-    return result;
+    return Err(Error::TooManyAudioCallbacks);
 }
 
-pub fn get_screen_height() -> i32 {
-    let result;
+// Only call this once raylib can no longer call the slot's trampoline.
+fn release_audio_callback_slot(index: usize) {
+    let mut slot = match AUDIO_CALLBACKS[index].lock() {
+        Ok(slot) => slot,
+        Err(poisoned) => poisoned.into_inner(),
+    };
 
-    unsafe {
-        result = GetScreenHeight();
-    }
+    *slot = None;
+}
 
-    return result;
+// A stream of 32-bit float samples, either pushed with update or pulled by a callback.
+// Multi-channel samples are interleaved.
+pub struct AudioStream<'a> {
+    inner: CAudioStream,
+    callback_slot: Option<usize>,
+    _device: PhantomData<&'a AudioDevice>,
 }
 
-pub fn get_fps() -> i32 {
-    let result;
+impl AudioStream<'_> {
+    fn to_caudio_stream(&self) -> CAudioStream {
+        return self.inner.clone();
+    }
 
-    unsafe {
-        result = GetFPS();
+    pub fn sample_rate(&self) -> u32 {
+        return self.inner.sample_rate;
     }
 
-    return result;
+    pub fn channels(&self) -> u32 {
+        return self.inner.channels;
+    }
+
+    // Whether the stream wants more samples from update.
+    pub fn is_processed(&self) -> bool {
+        let result;
+
+        unsafe {
+            result = IsAudioStreamProcessed(self.to_caudio_stream());
+        }
+
+        return result;
+    }
+
+    // Only call this when is_processed is true. Any trailing partial frame is ignored.
+    pub fn update(&self, samples: &[f32]) {
+        let frame_count = samples.len() / self.inner.channels as usize;
+
+        unsafe {
+            UpdateAudioStream(self.to_caudio_stream(), samples.as_ptr() as *const c_void, frame_count as c_int);
+        }
+    }
+
+    // The callback is asked to fill a buffer of interleaved samples whenever the stream needs more.
+    // It runs on the audio thread, so it should be quick and must not block.
+    pub fn set_callback<F: FnMut(&mut [f32]) + Send + 'static>(&mut self, callback: F) -> Result<(), Error> {
+        self.clear_callback();
+
+        let slot = claim_audio_callback_slot(self.inner.channels as usize, Box::new(callback))?;
+
+        unsafe {
+            SetAudioStreamCallback(self.to_caudio_stream(), Some(AUDIO_CALLBACK_TRAMPOLINES[slot]));
+        }
+
+        self.callback_slot = Some(slot);
+
+        return Ok(());
+    }
+
+    pub fn clear_callback(&mut self) {
+        if let Some(slot) = self.callback_slot.take() {
+            unsafe {
+                SetAudioStreamCallback(self.to_caudio_stream(), None);
+            }
+
+            release_audio_callback_slot(slot);
+        }
+    }
+
+    pub fn play(&self) {
+        unsafe {
+            PlayAudioStream(self.to_caudio_stream());
+        }
+    }
+
+    pub fn stop(&self) {
+        unsafe {
+            StopAudioStream(self.to_caudio_stream());
+        }
+    }
+
+    pub fn pause(&self) {
+        unsafe {
+            PauseAudioStream(self.to_caudio_stream());
+        }
+    }
+
+    pub fn resume(&self) {
+        unsafe {
+            ResumeAudioStream(self.to_caudio_stream());
+        }
+    }
+
+    pub fn is_playing(&self) -> bool {
+        let result;
+
+        unsafe {
+            result = IsAudioStreamPlaying(self.to_caudio_stream());
+        }
+
+        return result;
+    }
+
+    pub fn set_volume(&self, volume: f32) {
+        unsafe {
+            SetAudioStreamVolume(self.to_caudio_stream(), volume as c_float);
+        }
+    }
+
+    pub fn set_pitch(&self, pitch: f32) {
+        unsafe {
+            SetAudioStreamPitch(self.to_caudio_stream(), pitch as c_float);
+        }
+    }
+
+    pub fn set_pan(&self, pan: f32) {
+        unsafe {
+            SetAudioStreamPan(self.to_caudio_stream(), pan as c_float);
+        }
+    }
 }
 
-/*===================================
-             Font stuff.
-=====================================*/
+impl Drop for AudioStream<'_> {
+    fn drop(&mut self) {
+        self.clear_callback();
 
-pub struct Font {
-    inner: CFont,
+        unsafe {
+            UnloadAudioStream(self.to_caudio_stream());
+        }
+    }
 }
 
-impl Font {
-    fn to_cfont(&self) -> CFont {
-        return self.inner.clone();
+// Sees everything the device plays, after mixing, as interleaved stereo samples. Detached when dropped.
+pub struct MixedProcessor<'a> {
+    slot: usize,
+    _device: PhantomData<&'a AudioDevice>,
+}
+
+impl<'a> Drop for MixedProcessor<'a> {
+    fn drop(&mut self) {
+        unsafe {
+            DetachAudioMixedProcessor(AUDIO_CALLBACK_TRAMPOLINES[self.slot]);
+        }
+
+        release_audio_callback_slot(self.slot);
     }
 }
 
-pub fn get_default_font() -> Font {
-    let result: CFont;
+// Raylib always mixes in stereo.
+const AUDIO_DEVICE_CHANNELS: usize = 2;
 
-    unsafe {
-        result = GetFontDefault();
+impl AudioDevice {
+    pub fn load_music(&self, file_name: &str) -> Result<Music<'_>, Error> {
+        let converted_file_name = CString::new(file_name)?;
+        let result;
+
+        unsafe {
+            result = LoadMusicStream(converted_file_name.as_ptr());
+        }
+
+        return Music::from_cmusic(result, None, file_name);
     }
 
-    return Font { inner: result };
-}
+    // Keeps its own copy of data. file_type is the extension, including the dot, e.g. ".ogg".
+    pub fn load_music_from_memory(&self, file_type: &str, data: &[u8]) -> Result<Music<'_>, Error> {
+        let converted_file_type = CString::new(file_type)?;
+        let data = data.to_vec();
+        let result;
 
-pub fn set_text_line_spacing(spacing: i32) {
-    unsafe {
-        SetTextLineSpacing(spacing as c_int);
+        unsafe {
+            result = LoadMusicStreamFromMemory(converted_file_type.as_ptr(), data.as_ptr(), data.len() as c_int);
+        }
+
+        return Music::from_cmusic(result, Some(data), file_type);
+    }
+
+    pub fn load_audio_stream(&self, sample_rate: u32, channels: u32) -> Result<AudioStream<'_>, Error> {
+        let result;
+
+        unsafe {
+            result = LoadAudioStream(sample_rate as c_uint, 32, channels as c_uint);
+        }
+
+        let valid;
+
+        unsafe {
+            valid = IsAudioStreamValid(result.clone());
+        }
+
+        if !valid {
+            return Err(Error::ResourceLoadFailed("audio stream".to_string()));
+        }
+
+        return Ok(AudioStream { inner: result, callback_slot: None, _device: PhantomData });
+    }
+
+    // Processors run in the order they were attached, each seeing the last one's output.
+    pub fn attach_mixed_processor<F: FnMut(&mut [f32]) + Send + 'static>(&self, processor: F) -> Result<MixedProcessor<'_>, Error> {
+        let slot = claim_audio_callback_slot(AUDIO_DEVICE_CHANNELS, Box::new(processor))?;
+
+        unsafe {
+            AttachAudioMixedProcessor(AUDIO_CALLBACK_TRAMPOLINES[slot]);
+        }
+
+        return Ok(MixedProcessor { slot, _device: PhantomData });
     }
 }
 
-pub fn measure_text_ex(font: Font, text: &str, font_size: f32, spacing: f32) -> Vector2 {
-    let converted_text = CString::new(text).expect("Failed to create CString.");
-    let text_pointer = converted_text.as_ptr();
-    let result: CVector2;
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
 
-    unsafe {
-        result = MeasureTextEx(font.to_cfont(), text_pointer, font_size as c_float, spacing as c_float);
+    // f32 rounding grows with magnitude, so compare relative to the larger operand.
+    fn close(a: f32, b: f32, tolerance: f32) -> bool {
+        return f32::abs(a - b) <= tolerance * f32::max(1.0, f32::max(f32::abs(a), f32::abs(b)));
     }
 
-    return Vector2 { x: result.x, y: result.y };
+    fn close_v2(a: Vector2, b: Vector2, tolerance: f32) -> bool {
+        return close(a.x, b.x, tolerance) && close(a.y, b.y, tolerance);
+    }
+
+    fn close_v3(a: Vector3, b: Vector3, tolerance: f32) -> bool {
+        return close(a.x, b.x, tolerance) && close(a.y, b.y, tolerance) && close(a.z, b.z, tolerance);
+    }
+
+    fn close_matrix(a: Matrix, b: Matrix, tolerance: f32) -> bool {
+        return a.to_float_array().iter().zip(b.to_float_array().iter()).all(|(x, y)| close(*x, *y, tolerance));
+    }
+
+    fn any_vector2() -> impl Strategy<Value = Vector2> {
+        return (-1000.0f32..1000.0, -1000.0f32..1000.0).prop_map(|(x, y)| Vector2::new(x, y));
+    }
+
+    fn any_angle() -> impl Strategy<Value = f32> {
+        return -10.0f32..10.0;
+    }
+
+    fn any_vector3() -> impl Strategy<Value = Vector3> {
+        return (-1000.0f32..1000.0, -1000.0f32..1000.0, -1000.0f32..1000.0).prop_map(|(x, y, z)| Vector3::new(x, y, z));
+    }
+
+    fn any_axis() -> impl Strategy<Value = Vector3> {
+        return (-1.0f32..1.0, -1.0f32..1.0, -1.0f32..1.0)
+            .prop_map(|(x, y, z)| Vector3::new(x, y, z))
+            .prop_filter("axis must not be near zero", |v| v.len() > 0.1)
+            .prop_map(|v| v.normalized());
+    }
+
+    fn any_quaternion() -> impl Strategy<Value = Quaternion> {
+        return (any_axis(), any_angle()).prop_map(|(axis, angle)| Quaternion::from_axis_angle(axis, angle));
+    }
+
+    fn any_matrix() -> impl Strategy<Value = Matrix> {
+        return proptest::array::uniform16(-10.0f32..10.0).prop_map(Matrix::from_float_array);
+    }
+
+    // Rotation, scale and translation keep the matrix well conditioned enough to invert in f32.
+    fn any_affine_matrix() -> impl Strategy<Value = Matrix> {
+        return (any_axis(), any_angle(), 0.5f32..2.0, any_vector3())
+            .prop_map(|(axis, angle, scale, t)| Matrix::scale(scale, scale, scale) * Matrix::rotate(axis, angle) * Matrix::translate(t.x, t.y, t.z));
+    }
+
+    proptest! {
+        #[test]
+        fn vector2_add_sub_are_inverse(a in any_vector2(), b in any_vector2()) {
+            prop_assert!(close_v2(a + b - b, a, 1e-4));
+            prop_assert!(close_v2(a - b + b, a, 1e-4));
+            prop_assert_eq!(a + -a, Vector2::ZERO);
+        }
+
+        #[test]
+        fn vector2_add_is_associative(a in any_vector2(), b in any_vector2(), c in any_vector2()) {
+            prop_assert!(close_v2((a + b) + c, a + (b + c), 1e-4));
+        }
+
+        #[test]
+        fn vector2_normalized_has_unit_len(v in any_vector2()) {
+            prop_assume!(v.len() > 1e-3);
+
+            let n = v.normalized();
+            prop_assert!(close(n.len(), 1.0, 1e-5));
+            prop_assert!(close(n.dot(v), v.len(), 1e-4));
+        }
+
+        #[test]
+        fn vector2_rotation_keeps_len_and_undoes(v in any_vector2(), angle in any_angle()) {
+            let rotated = v.rotated(angle);
+
+            prop_assert!(close(rotated.len(), v.len(), 1e-4));
+            prop_assert!(close_v2(rotated.rotated(-angle), v, 1e-3));
+        }
+
+        #[test]
+        fn vector2_lerp_hits_endpoints(a in any_vector2(), b in any_vector2()) {
+            prop_assert_eq!(a.lerp(b, 0.0), a);
+            prop_assert!(close_v2(a.lerp(b, 1.0), b, 1e-4));
+            prop_assert!(close_v2(a.lerp(b, 0.5), (a + b) / 2.0, 1e-4));
+        }
+
+        #[test]
+        fn vector2_project_and_reject_split_the_vector(v in any_vector2(), onto in any_vector2()) {
+            prop_assume!(onto.len() > 1e-1);
+
+            let along = v.project(onto);
+            let across = v.reject(onto);
+
+            prop_assert!(close_v2(along + across, v, 1e-3));
+            prop_assert!(close(across.dot(onto) / (onto.len() * f32::max(1.0, v.len())), 0.0, 1e-3));
+        }
+
+        #[test]
+        fn vector2_clamp_len_bounds_len(v in any_vector2(), min in 0.0f32..10.0, extra in 0.0f32..100.0) {
+            prop_assume!(v.len() > 1e-3);
+
+            let max = min + extra;
+            let len = v.clamp_len(min, max).len();
+
+            prop_assert!(len >= min * (1.0 - 1e-4) && len <= max * (1.0 + 1e-4));
+        }
+
+        #[test]
+        fn vector3_cross_is_perpendicular(a in any_vector3(), b in any_vector3()) {
+            let c = a.cross(b);
+            let scale = f32::max(1.0, a.len() * b.len() * c.len());
+
+            prop_assert!(close(c.dot(a) / scale, 0.0, 1e-4));
+            prop_assert!(close(c.dot(b) / scale, 0.0, 1e-4));
+            prop_assert!(close_v3(b.cross(a), -c, 1e-4));
+        }
+
+        #[test]
+        fn vector3_normalized_has_unit_len(v in any_vector3()) {
+            prop_assume!(v.len() > 1e-3);
+
+            prop_assert!(close(v.normalized().len(), 1.0, 1e-5));
+        }
+
+        #[test]
+        fn quaternion_normalized_has_unit_len(x in -10.0f32..10.0, y in -10.0f32..10.0, z in -10.0f32..10.0, w in -10.0f32..10.0) {
+            let q = Quaternion::new(x, y, z, w);
+            prop_assume!(q.len() > 1e-3);
+
+            prop_assert!(close(q.normalized().len(), 1.0, 1e-5));
+        }
+
+        #[test]
+        fn quaternion_times_inverse_is_identity(q in any_quaternion()) {
+            prop_assert!(close(q.len(), 1.0, 1e-5));
+            let product = q * q.invert();
+            prop_assert!(close(product.x, 0.0, 1e-5) && close(product.y, 0.0, 1e-5) && close(product.z, 0.0, 1e-5) && close(product.w, 1.0, 1e-5));
+
+            let v = Vector3::new(1.0, 2.0, 3.0);
+            prop_assert!(close_v3(v.rotate_by_quaternion(q).rotate_by_quaternion(q.invert()), v, 1e-4));
+        }
+
+        #[test]
+        fn quaternion_matches_axis_angle_and_matrix(axis in any_axis(), angle in any_angle(), v in any_vector3()) {
+            let q = Quaternion::from_axis_angle(axis, angle);
+            let rotated = v.rotate_by_quaternion(q);
+
+            prop_assert!(close_v3(rotated, v.rotate_by_axis_angle(axis, angle), 1e-3));
+            prop_assert!(close_v3(rotated, v.transform(q.to_matrix()), 1e-3));
+            prop_assert!(close_v3(rotated, v.transform(Matrix::rotate(axis, angle)), 1e-3));
+            prop_assert!(close(rotated.len(), v.len(), 1e-4));
+        }
+
+        #[test]
+        fn quaternion_axis_angle_round_trip(axis in any_axis(), angle in 0.01f32..3.13) {
+            let (out_axis, out_angle) = Quaternion::from_axis_angle(axis, angle).to_axis_angle();
+
+            prop_assert!(close(out_angle, angle, 1e-3));
+            prop_assert!(close_v3(out_axis, axis, 1e-2));
+        }
+
+        #[test]
+        fn quaternion_euler_round_trip(pitch in -1.5f32..1.5, yaw in -1.5f32..1.5, roll in -1.5f32..1.5) {
+            let euler = Quaternion::from_euler(pitch, yaw, roll).to_euler();
+
+            prop_assert!(close_v3(euler, Vector3::new(pitch, yaw, roll), 1e-3));
+        }
+
+        #[test]
+        fn quaternion_matrix_round_trip(q in any_quaternion()) {
+            let round_trip = Quaternion::from_matrix(q.to_matrix());
+
+            // q and -q are the same rotation.
+            prop_assert!(close(f32::abs(round_trip.dot(q)), 1.0, 1e-4));
+        }
+
+        #[test]
+        fn matrix_mul_is_associative(a in any_matrix(), b in any_matrix(), c in any_matrix()) {
+            prop_assert!(close_matrix((a * b) * c, a * (b * c), 1e-3));
+        }
+
+        #[test]
+        fn matrix_times_inverse_is_identity(m in any_affine_matrix()) {
+            prop_assert!(close_matrix(m * m.invert(), Matrix::identity(), 1e-3));
+            prop_assert!(close_matrix(m.invert() * m, Matrix::identity(), 1e-3));
+            prop_assert!(close(m.determinant() * m.invert().determinant(), 1.0, 1e-3));
+        }
+
+        #[test]
+        fn matrix_transpose_is_an_involution(m in any_matrix()) {
+            prop_assert_eq!(m.transpose().transpose(), m);
+            prop_assert!(close(m.transpose().determinant(), m.determinant(), 1e-3));
+        }
+    }
+
+    #[test]
+    fn vector2_normalize_leaves_zero_alone() {
+        assert_eq!(Vector2::ZERO.normalized(), Vector2::ZERO);
+    }
 }
@@ -0,0 +1,129 @@
+// Needs an audio device, so only runs with --features null-audio, which works without a sound card.
+#![cfg(feature = "null-audio")]
+
+mod common;
+
+use std::sync::Mutex;
+
+use adhocrays::*;
+
+// Raylib's audio device is global, so the tests take turns with it.
+static DEVICE_LOCK: Mutex<()> = Mutex::new(());
+
+fn with_device(test: impl FnOnce(&AudioDevice)) {
+    let _lock = DEVICE_LOCK.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+    let device = init_audio_device().unwrap();
+
+    test(&device);
+}
+
+fn one_second_wave() -> Wave {
+    return Wave::load_from_memory(".wav", &common::sine_wav(44100, 2, 44100)).unwrap();
+}
+
+#[test]
+fn only_one_device_at_a_time() {
+    with_device(|_| {
+        assert!(matches!(init_audio_device(), Err(Error::AudioDeviceAlreadyOpen)));
+    });
+
+    // Dropping the device frees the slot for the next one.
+    with_device(|_| {});
+}
+
+#[test]
+fn master_volume_round_trips() {
+    with_device(|device| {
+        device.set_master_volume(0.25);
+        assert!((device.get_master_volume() - 0.25).abs() < 0.001);
+
+        device.set_master_volume(1.0);
+    });
+}
+
+#[test]
+fn sound_from_wave_plays_and_stops() {
+    with_device(|device| {
+        let wave = one_second_wave();
+        let sound = device.load_sound_from_wave(&wave).unwrap();
+
+        // Resampled to the device's rate, so the frame count may not match the wave's.
+        assert!(sound.frame_count() > 0);
+        assert!(!sound.is_playing());
+
+        sound.play();
+        assert!(sound.is_playing());
+
+        sound.pause();
+        assert!(!sound.is_playing());
+
+        sound.resume();
+        assert!(sound.is_playing());
+
+        sound.stop();
+        assert!(!sound.is_playing());
+    });
+}
+
+#[test]
+fn alias_plays_independently() {
+    with_device(|device| {
+        let wave = one_second_wave();
+        let sound = device.load_sound_from_wave(&wave).unwrap();
+        let alias = device.load_sound_alias(&sound).unwrap();
+
+        alias.play();
+        assert!(alias.is_playing());
+        assert!(!sound.is_playing());
+
+        alias.stop();
+    });
+}
+
+#[test]
+fn load_sound_rejects_missing_file() {
+    with_device(|device| {
+        assert!(matches!(device.load_sound("does/not/exist.wav"), Err(Error::ResourceLoadFailed(_))));
+    });
+}
+
+#[test]
+fn music_from_memory_plays() {
+    with_device(|device| {
+        let music = device.load_music_from_memory(".wav", &common::sine_wav(44100, 2, 44100)).unwrap();
+
+        assert!((music.time_length() - 1.0).abs() < 0.01);
+
+        music.play();
+        music.update();
+        assert!(music.is_playing());
+
+        music.stop();
+        assert!(!music.is_playing());
+    });
+}
+
+#[test]
+fn stream_callback_slots_are_released() {
+    with_device(|device| {
+        let mut streams = Vec::new();
+
+        // Every slot gets taken, and then one more is asked for.
+        loop {
+            let mut stream = device.load_audio_stream(44100, 1).unwrap();
+
+            match stream.set_callback(|samples| samples.fill(0.0)) {
+                Ok(()) => streams.push(stream),
+                Err(Error::TooManyAudioCallbacks) => break,
+                Err(e) => panic!("unexpected error: {}", e),
+            }
+        }
+
+        assert!(!streams.is_empty());
+
+        streams.pop();
+
+        let mut stream = device.load_audio_stream(44100, 1).unwrap();
+        assert!(stream.set_callback(|samples| samples.fill(0.0)).is_ok());
+    });
+}
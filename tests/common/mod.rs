@@ -0,0 +1,33 @@
+// Builds a 16-bit PCM .wav in memory, so the audio tests don't need any asset files.
+// Every channel carries the same 440 Hz sine at half volume.
+pub fn sine_wav(sample_rate: u32, channels: u16, frame_count: u32) -> Vec<u8> {
+    let data_len = frame_count * channels as u32 * 2;
+    let mut wav = Vec::with_capacity(44 + data_len as usize);
+
+    wav.extend_from_slice(b"RIFF");
+    wav.extend_from_slice(&(36 + data_len).to_le_bytes());
+    wav.extend_from_slice(b"WAVE");
+
+    wav.extend_from_slice(b"fmt ");
+    wav.extend_from_slice(&16u32.to_le_bytes());
+    wav.extend_from_slice(&1u16.to_le_bytes());                                  // PCM.
+    wav.extend_from_slice(&channels.to_le_bytes());
+    wav.extend_from_slice(&sample_rate.to_le_bytes());
+    wav.extend_from_slice(&(sample_rate * channels as u32 * 2).to_le_bytes());   // Bytes per second.
+    wav.extend_from_slice(&(channels * 2).to_le_bytes());                        // Bytes per frame.
+    wav.extend_from_slice(&16u16.to_le_bytes());                                 // Bits per sample.
+
+    wav.extend_from_slice(b"data");
+    wav.extend_from_slice(&data_len.to_le_bytes());
+
+    for frame in 0..frame_count {
+        let t = frame as f32 / sample_rate as f32;
+        let sample = (f32::sin(2.0 * std::f32::consts::PI * 440.0 * t) * i16::MAX as f32 * 0.5) as i16;
+
+        for _ in 0..channels {
+            wav.extend_from_slice(&sample.to_le_bytes());
+        }
+    }
+
+    return wav;
+}
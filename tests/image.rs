@@ -0,0 +1,179 @@
+// Images live in CPU memory, so none of these need a window.
+
+use adhocrays::*;
+
+fn solid(width: i32, height: i32, color: Color) -> Image {
+    return Image::gen_color(width, height, color).unwrap();
+}
+
+#[test]
+fn gen_color_fills_every_pixel() {
+    let image = solid(4, 3, colors::RED);
+
+    assert_eq!(image.width(), 4);
+    assert_eq!(image.height(), 3);
+    assert_eq!(image.format(), PixelFormat::UncompressedR8G8B8A8);
+
+    let pixels = image.pixels().unwrap();
+    assert_eq!(pixels.len(), 12);
+    assert!(pixels.iter().all(|p| *p == colors::RED));
+}
+
+#[test]
+fn gen_color_rejects_negative_size() {
+    assert!(matches!(Image::gen_color(-1, 4, colors::RED), Err(Error::InvalidSize(-1, 4))));
+    assert!(matches!(Image::gen_color(4, -1, colors::RED), Err(Error::InvalidSize(4, -1))));
+}
+
+#[test]
+fn pixels_mut_writes_are_visible() {
+    let mut image = solid(2, 2, colors::BLACK);
+
+    image.pixels_mut().unwrap()[3] = colors::WHITE;
+
+    assert_eq!(image.get_color(1, 1), colors::WHITE);
+    assert_eq!(image.get_color(0, 0), colors::BLACK);
+}
+
+#[test]
+fn pixels_only_for_rgba8() {
+    let mut image = solid(2, 2, colors::BLUE);
+
+    image.set_format(PixelFormat::UncompressedGrayscale);
+    assert!(image.pixels().is_none());
+
+    image.set_format(PixelFormat::UncompressedR8G8B8A8);
+    assert_eq!(image.pixels().unwrap().len(), 4);
+}
+
+#[test]
+fn crop_and_from_image() {
+    let mut image = solid(8, 8, colors::BLACK);
+    image.draw_pixel(2, 3, colors::WHITE);
+
+    let copy = image.from_image(Rectangle::new(2.0, 3.0, 2.0, 2.0));
+    assert_eq!((copy.width(), copy.height()), (2, 2));
+    assert_eq!(copy.get_color(0, 0), colors::WHITE);
+
+    image.crop(Rectangle::new(2.0, 3.0, 4.0, 4.0));
+    assert_eq!((image.width(), image.height()), (4, 4));
+    assert_eq!(image.get_color(0, 0), colors::WHITE);
+}
+
+#[test]
+fn from_image_clamps_to_bounds() {
+    let mut image = solid(8, 8, colors::BLACK);
+    image.draw_pixel(7, 0, colors::WHITE);
+
+    let copy = image.from_image(Rectangle::new(6.0, -2.0, 4.0, 4.0));
+    assert_eq!((copy.width(), copy.height()), (2, 2));
+    assert_eq!(copy.get_color(1, 0), colors::WHITE);
+
+    let copy = image.from_image(Rectangle::new(10.0, 10.0, 2.0, 2.0));
+    assert_eq!((copy.width(), copy.height()), (0, 0));
+}
+
+#[test]
+fn resize_changes_dimensions() {
+    let mut image = solid(4, 4, colors::GREEN);
+
+    image.resize(8, 2).unwrap();
+    assert_eq!((image.width(), image.height()), (8, 2));
+
+    image.resize_nn(3, 5).unwrap();
+    assert_eq!((image.width(), image.height()), (3, 5));
+    assert!(image.pixels().unwrap().iter().all(|p| *p == colors::GREEN));
+}
+
+#[test]
+fn resize_rejects_negative_size() {
+    let mut image = solid(4, 4, colors::GREEN);
+
+    assert!(matches!(image.resize(-2, 2), Err(Error::InvalidSize(-2, 2))));
+    assert!(matches!(image.resize_nn(2, -2), Err(Error::InvalidSize(2, -2))));
+    assert!(matches!(image.resize_canvas(-1, -1, 0, 0, colors::BLANK), Err(Error::InvalidSize(-1, -1))));
+    assert_eq!((image.width(), image.height()), (4, 4));
+}
+
+#[test]
+fn resize_canvas_fills_new_area() {
+    let mut image = solid(2, 2, colors::RED);
+
+    image.resize_canvas(4, 4, 1, 1, colors::BLUE).unwrap();
+
+    assert_eq!((image.width(), image.height()), (4, 4));
+    assert_eq!(image.get_color(0, 0), colors::BLUE);
+    assert_eq!(image.get_color(1, 1), colors::RED);
+    assert_eq!(image.get_color(3, 3), colors::BLUE);
+}
+
+#[test]
+fn flips_and_rotations_move_pixels() {
+    let mut image = solid(4, 2, colors::BLACK);
+    image.draw_pixel(0, 0, colors::WHITE);
+
+    image.flip_vertical();
+    assert_eq!(image.get_color(0, 1), colors::WHITE);
+
+    image.flip_horizontal();
+    assert_eq!(image.get_color(3, 1), colors::WHITE);
+
+    image.rotate_cw();
+    assert_eq!((image.width(), image.height()), (2, 4));
+
+    image.rotate_ccw();
+    assert_eq!((image.width(), image.height()), (4, 2));
+    assert_eq!(image.get_color(3, 1), colors::WHITE);
+}
+
+#[test]
+fn color_invert_and_replace() {
+    let mut image = solid(2, 2, Color { r: 10, g: 20, b: 30, a: 255 });
+
+    image.color_invert();
+    assert_eq!(image.get_color(0, 0), Color { r: 245, g: 235, b: 225, a: 255 });
+
+    image.color_replace(Color { r: 245, g: 235, b: 225, a: 255 }, colors::MAGENTA);
+    assert_eq!(image.get_color(1, 1), colors::MAGENTA);
+}
+
+#[test]
+fn draw_rectangle_stays_inside_bounds() {
+    let mut image = solid(4, 4, colors::BLACK);
+
+    image.draw_rectangle(1, 1, 2, 2, colors::WHITE);
+
+    let white = image.pixels().unwrap().iter().filter(|p| **p == colors::WHITE).count();
+    assert_eq!(white, 4);
+    assert_eq!(image.get_color(0, 0), colors::BLACK);
+    assert_eq!(image.get_color(2, 2), colors::WHITE);
+}
+
+#[test]
+fn clone_is_independent() {
+    let original = solid(2, 2, colors::RED);
+    let mut copy = original.clone();
+
+    copy.clear_background(colors::BLUE);
+
+    assert_eq!(original.get_color(0, 0), colors::RED);
+    assert_eq!(copy.get_color(0, 0), colors::BLUE);
+}
+
+#[test]
+fn png_round_trip() {
+    let mut image = solid(3, 2, colors::BLACK);
+    image.draw_pixel(2, 1, colors::WHITE);
+
+    let png = image.export_to_memory(".png").unwrap();
+    let loaded = Image::load_from_memory(".png", &png).unwrap();
+
+    assert_eq!((loaded.width(), loaded.height()), (3, 2));
+    assert_eq!(loaded.get_color(2, 1), colors::WHITE);
+    assert_eq!(loaded.get_color(0, 0), colors::BLACK);
+}
+
+#[test]
+fn load_from_memory_rejects_garbage() {
+    assert!(matches!(Image::load_from_memory(".png", b"not a png"), Err(Error::ResourceLoadFailed(_))));
+}
@@ -0,0 +1,152 @@
+// Waves live in main memory, so none of these need an audio device.
+
+mod common;
+
+use adhocrays::*;
+
+fn stereo_wave(frame_count: u32) -> Wave {
+    return Wave::load_from_memory(".wav", &common::sine_wav(44100, 2, frame_count)).unwrap();
+}
+
+fn float_wave(frame_count: u32) -> Wave {
+    let mut wave = stereo_wave(frame_count);
+    wave.format(44100, 32, 2).unwrap();
+
+    return wave;
+}
+
+#[test]
+fn load_from_memory_reads_the_header() {
+    let wave = stereo_wave(1000);
+
+    assert_eq!(wave.frame_count(), 1000);
+    assert_eq!(wave.sample_rate(), 44100);
+    assert_eq!(wave.sample_size(), 16);
+    assert_eq!(wave.channels(), 2);
+}
+
+#[test]
+fn load_from_memory_rejects_garbage() {
+    assert!(matches!(Wave::load_from_memory(".wav", b"not a wav"), Err(Error::ResourceLoadFailed(_))));
+}
+
+#[test]
+fn crop_keeps_the_given_frames() {
+    let mut wave = float_wave(1000);
+    let expected = wave.samples().unwrap()[200..400].to_vec();
+
+    wave.crop(100, 200);
+
+    assert_eq!(wave.frame_count(), 100);
+    assert_eq!(wave.samples().unwrap(), &expected[..]);
+}
+
+#[test]
+fn crop_out_of_bounds_is_ignored() {
+    let mut wave = stereo_wave(1000);
+
+    wave.crop(0, 1001);
+    assert_eq!(wave.frame_count(), 1000);
+
+    wave.crop(500, 500);
+    assert_eq!(wave.frame_count(), 1000);
+
+    wave.crop(600, 500);
+    assert_eq!(wave.frame_count(), 1000);
+
+    wave.crop(0, 1000);
+    assert_eq!(wave.frame_count(), 1000);
+}
+
+#[test]
+fn samples_only_for_32_bit() {
+    let mut wave = stereo_wave(100);
+    assert!(wave.samples().is_none());
+    assert!(wave.samples_mut().is_none());
+
+    wave.format(44100, 32, 2).unwrap();
+    assert_eq!(wave.samples().unwrap().len(), 200);
+}
+
+#[test]
+fn load_samples_len_and_range() {
+    let wave = stereo_wave(1000);
+    let samples = wave.load_samples();
+
+    assert_eq!(samples.len(), 2000);
+    assert!(samples.iter().all(|s| (-1.0..=1.0).contains(s)));
+
+    // The generator writes the same sine to both channels at half volume.
+    let peak = samples.iter().fold(0.0f32, |peak, s| peak.max(s.abs()));
+    assert!((peak - 0.5).abs() < 0.01);
+    assert!(samples.chunks(2).all(|frame| frame[0] == frame[1]));
+}
+
+#[test]
+fn format_round_trips_sample_size() {
+    let mut wave = stereo_wave(1000);
+    let original = wave.load_samples();
+
+    wave.format(44100, 32, 2).unwrap();
+    wave.format(44100, 16, 2).unwrap();
+
+    assert_eq!(wave.sample_size(), 16);
+    assert_eq!(wave.frame_count(), 1000);
+
+    let round_trip = wave.load_samples();
+    assert!(original.iter().zip(round_trip.iter()).all(|(a, b)| (a - b).abs() < 0.001));
+}
+
+#[test]
+fn format_converts_channels_and_rate() {
+    let mut wave = stereo_wave(44100);
+
+    wave.format(44100, 8, 1).unwrap();
+    assert_eq!((wave.sample_size(), wave.channels()), (8, 1));
+    assert_eq!(wave.load_samples().len(), 44100);
+
+    wave.format(22050, 8, 1).unwrap();
+    assert_eq!(wave.sample_rate(), 22050);
+    // The resampler may round the length a little either way.
+    assert!((wave.frame_count() as i64 - 22050).abs() < 100);
+}
+
+#[test]
+fn format_rejects_invalid_arguments() {
+    let mut wave = stereo_wave(100);
+
+    assert!(matches!(wave.format(44100, 24, 2), Err(Error::InvalidAudioFormat(44100, 24, 2))));
+    assert!(matches!(wave.format(44100, 16, 0), Err(Error::InvalidAudioFormat(44100, 16, 0))));
+    assert!(matches!(wave.format(0, 16, 2), Err(Error::InvalidAudioFormat(0, 16, 2))));
+    assert!(matches!(wave.format(u32::MAX, 16, 2), Err(Error::InvalidAudioFormat(_, 16, 2))));
+    assert!(matches!(wave.format(44100, 16, 1000), Err(Error::InvalidAudioFormat(44100, 16, 1000))));
+
+    assert_eq!((wave.sample_rate(), wave.sample_size(), wave.channels(), wave.frame_count()), (44100, 16, 2, 100));
+}
+
+#[test]
+fn clone_is_independent() {
+    let original = float_wave(100);
+    let mut copy = original.clone();
+
+    copy.samples_mut().unwrap().fill(0.0);
+
+    assert!(copy.samples().unwrap().iter().all(|s| *s == 0.0));
+    assert!(original.samples().unwrap().iter().any(|s| *s != 0.0));
+    assert_eq!(copy.frame_count(), original.frame_count());
+}
+
+#[test]
+fn wav_export_round_trip() {
+    let wave = stereo_wave(500);
+    let path = std::env::temp_dir().join(format!("adhocrays-wave-{}.wav", std::process::id()));
+    let path = path.to_str().unwrap();
+
+    wave.export(path).unwrap();
+    let loaded = Wave::load(path);
+    std::fs::remove_file(path).unwrap();
+
+    let loaded = loaded.unwrap();
+    assert_eq!((loaded.frame_count(), loaded.sample_rate(), loaded.channels()), (500, 44100, 2));
+    assert_eq!(loaded.load_samples(), wave.load_samples());
+}